@@ -0,0 +1,45 @@
+//! Find the cheapest way to win the battle of day 22 on the puzzle input, and replay it turn by
+//! turn as in the puzzle text. The spells are read from `day22_spells.toml` in the inputs
+//! directory, or from another file, to try other spells or balance changes.
+//!
+//! ```text
+//! cargo run --release --example day22_replay [--hard] [--spells <path>]
+//! ```
+
+use advent_of_code_2015::day22::{self, Battle, Difficulty, Unit, PLAYER_HP, PLAYER_MANA};
+use advent_of_code_2015::inputs::Inputs;
+use std::env;
+use std::error::Error;
+use std::fs;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let inputs = Inputs::resolve(None, None);
+    let mut difficulty = Difficulty::Normal;
+    let mut spells = fs::read_to_string(inputs.spells_path()).unwrap_or_else(|_| day22::SPELLS.to_string());
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--hard" => difficulty = Difficulty::Hard,
+            "--spells" => {
+                let path = args.next().ok_or("Missing path of the spells.")?;
+                spells = fs::read_to_string(&path)?;
+            },
+            _ => return Err(format!("Unknown argument: {}.", arg).into()),
+        }
+    }
+
+    let spells = day22::read_spells(&spells)?;
+    let boss = day22::read_boss_file(&inputs.read(22)?)?;
+    let battle = Battle::new(&Unit::new(PLAYER_HP, PLAYER_MANA, 0), &boss, difficulty, &spells);
+
+    let (win, stats) = day22::search(&battle);
+    let win = win.ok_or_else(|| format!("The player can't win ({})", stats))?;
+    println!("Spells: {}", win.cast_names().join(", "));
+    println!("Mana spent: {}", win.state.value());
+    println!("Search: {}", stats);
+    println!();
+    print!("{}", battle.replay(&win.casts).unwrap());
+
+    Ok(())
+}
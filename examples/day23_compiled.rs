@@ -0,0 +1,70 @@
+//! Compare the interpreter of day 23 with the compiled execution, on the puzzle input with
+//! bigger and bigger starting values in register `a`.
+//!
+//! ```text
+//! cargo run --release --example day23_compiled [<runs>]
+//! ```
+
+use advent_of_code_2015::day23::{CompiledProgram, Computer, Limits};
+use advent_of_code_2015::inputs::Inputs;
+use std::env;
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+const STARTS: [i64; 5] = [0, 1_000, 1_000_000, 1_000_000_000, 1_000_000_000_000];
+
+/* Median time of running the program `runs` times from the start, with the
+ * given value in register `a`. */
+fn measure<F>(computer: &Computer, a: i64, runs: usize, mut run: F) -> (Duration, i64)
+    where F: FnMut(&mut Computer) {
+
+    let mut durations = Vec::with_capacity(runs);
+    let mut result = 0;
+
+    for _ in 0..runs {
+        let mut computer = Computer {
+            registers: vec![a, 0],
+            memory: computer.memory.clone(),
+            instruction_pointer: 0,
+            output: Vec::new(),
+            instruction_set: computer.instruction_set.clone(),
+        };
+
+        let start = Instant::now();
+        run(&mut computer);
+        durations.push(start.elapsed());
+        result = computer.registers[1];
+    }
+
+    durations.sort();
+    (durations[durations.len() / 2], result)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let runs = match env::args().nth(1) {
+        Some(runs) => runs.parse()?,
+        None => 1000,
+    };
+
+    let mut computer = Computer::new();
+    computer.load_program(&Inputs::resolve(None, None).read(23)?)?;
+    let program = CompiledProgram::new(&computer.memory);
+    let limits = Limits::default();
+
+    println!("{:>16}  {:>8}  {:>16}  {:>16}  {:>8}",
+        "a", "b", "Interpreter (µs)", "Compiled (µs)", "Speedup");
+
+    for &a in STARTS.iter() {
+        let (interpreted, b) = measure(&computer, a, runs, |computer| { computer.run(&limits); });
+        let (compiled, compiled_b) = measure(&computer, a, runs, |computer| {
+            program.run(computer, &limits);
+        });
+        assert_eq!(b, compiled_b);
+
+        println!("{:>16}  {:>8}  {:>16.3}  {:>16.3}  {:>7.1}x", a, b,
+            interpreted.as_secs_f64() * 1e6, compiled.as_secs_f64() * 1e6,
+            interpreted.as_secs_f64() / compiled.as_secs_f64());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! Benchmarks of the solvers, and baselines to compare them with.
+
+use crate::error::{parse_token, next_token, ParseError};
+use crate::report::Answer;
+use crate::solver::{Part, Result, Solver};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Wall times of several runs of one part of a puzzle.
+#[derive(Clone, Debug)]
+pub struct Timings {
+    /// Day of the puzzle.
+    pub day: usize,
+    /// Part of the puzzle.
+    pub part: Part,
+    /// Shortest time of the runs.
+    pub min: Duration,
+    /// Median time of the runs.
+    pub median: Duration,
+    /// Longest time of the runs.
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Solve one part of a puzzle `runs` times (at least once).
+    pub fn measure(day: usize, solver: &dyn Solver, part: Part, input: &str, runs: usize)
+        -> Result<Timings> {
+
+        let mut durations = Vec::with_capacity(runs);
+
+        for _ in 0..runs.max(1) {
+            durations.push(Answer::solve(day, solver, part, input)?.duration);
+        }
+
+        Ok(Timings::from_durations(day, part, durations))
+    }
+
+    /// Summarize the durations of several runs, which must not be empty.
+    pub fn from_durations(day: usize, part: Part, mut durations: Vec<Duration>) -> Timings {
+        durations.sort();
+
+        /* With an even number of runs, the median is the mean of the two middle
+         * values. */
+        let middle = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[middle - 1] + durations[middle]) / 2
+        } else {
+            durations[middle]
+        };
+
+        Timings {
+            day,
+            part,
+            min: durations[0],
+            median,
+            max: durations[durations.len() - 1],
+        }
+    }
+}
+
+/// Median times of a previous benchmark, to compare against.
+#[derive(Clone, Debug, Default)]
+pub struct Baseline(HashMap<(usize, Part), Duration>);
+
+impl Baseline {
+    /// Read a baseline saved by `to_tsv`.
+    pub fn from_tsv(contents: &str) -> std::result::Result<Baseline, ParseError> {
+        let mut baseline = HashMap::new();
+
+        /* The first line is the header. */
+        for (idx, line) in contents.lines().enumerate().skip(1) {
+            let (day, part, median) = read_line(line).map_err(|e| e.at_line(idx + 1))?;
+            baseline.insert((day, part), median);
+        }
+
+        Ok(Baseline(baseline))
+    }
+
+    /// Median time of one part of a puzzle in the baseline.
+    pub fn get(&self, day: usize, part: Part) -> Option<Duration> {
+        self.0.get(&(day, part)).cloned()
+    }
+}
+
+fn read_line(line: &str) -> std::result::Result<(usize, Part, Duration), ParseError> {
+    let mut iter = line.split('\t');
+
+    let day = parse_token(line, next_token(&mut iter, line, "day")?)?;
+    let token = next_token(&mut iter, line, "part")?;
+    let part = parse_token(line, token).ok().and_then(Part::from_number)
+        .ok_or_else(|| ParseError::new(line, token, "Invalid part."))?;
+
+    /* Only the median is kept; the minimum comes first. */
+    next_token(&mut iter, line, "minimum time")?;
+    let token = next_token(&mut iter, line, "median time")?;
+    let median: f64 = parse_token(line, token)?;
+    if !median.is_finite() || median < 0.0 {
+        return Err(ParseError::new(line, token, "Invalid time."));
+    }
+
+    Ok((day, part, Duration::from_secs_f64(median / 1000.0)))
+}
+
+/// Save timings as tab-separated values (times in milliseconds), to be used as
+/// a baseline later.
+pub fn to_tsv(timings: &[Timings]) -> String {
+    let mut output = String::from("day\tpart\tmin_ms\tmedian_ms\tmax_ms\n");
+
+    for t in timings {
+        writeln!(output, "{}\t{}\t{:.3}\t{:.3}\t{:.3}",
+            t.day, t.part.number(), milliseconds(t.min), milliseconds(t.median), milliseconds(t.max)).unwrap();
+    }
+
+    output
+}
+
+/// Format timings as a table, comparing the medians to the baseline if any.
+pub fn to_table(timings: &[Timings], baseline: Option<&Baseline>) -> String {
+    let mut output = format!("{:>3}  {:>4}  {:>14}  {:>14}  {:>14}",
+        "Day", "Part", "Min (ms)", "Median (ms)", "Max (ms)");
+    if baseline.is_some() {
+        write!(output, "  {:>12}", "vs baseline").unwrap();
+    }
+    output.push('\n');
+
+    for t in timings {
+        write!(output, "{:>3}  {:>4}  {:>14.3}  {:>14.3}  {:>14.3}",
+            t.day, t.part.number(), milliseconds(t.min), milliseconds(t.median), milliseconds(t.max)).unwrap();
+
+        match baseline.map(|b| b.get(t.day, t.part)) {
+            Some(Some(previous)) if previous > Duration::default() => {
+                let change = milliseconds(t.median) / milliseconds(previous) - 1.0;
+                write!(output, "  {:>+11.1}%", change * 100.0).unwrap();
+            },
+            Some(_) => write!(output, "  {:>12}", "n/a").unwrap(),
+            None => (),
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trip() {
+        let durations = [5, 1, 3, 2].iter().map(|&x| Duration::from_millis(x)).collect();
+        let timings = Timings::from_durations(4, Part::Two, durations);
+        assert_eq!(timings.min, Duration::from_millis(1));
+        assert_eq!(timings.median, Duration::from_micros(2500));
+        assert_eq!(timings.max, Duration::from_millis(5));
+
+        let baseline = Baseline::from_tsv(&to_tsv(&[timings])).unwrap();
+        assert_eq!(baseline.get(4, Part::Two), Some(Duration::from_micros(2500)));
+        assert_eq!(baseline.get(4, Part::One), None);
+    }
+}
@@ -0,0 +1,544 @@
+use advent_of_code_2015::bench::{self, Baseline, Timings};
+use advent_of_code_2015::day22::{self, CustomSpells};
+use advent_of_code_2015::day23::{self, Computer, ControlFlowGraph, Debugger, Limits, Stop};
+use advent_of_code_2015::inputs::{self, Inputs};
+use advent_of_code_2015::report::{Answer, Format};
+use advent_of_code_2015::verify::{ExpectedAnswers, Verdict};
+use advent_of_code_2015::{solver, ParseError, Part, Solver, NBR_DAYS};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+use std::process;
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--input-str <input>]
+               [--format <text|json|tsv>] [<inputs>]
+       aoc verify [<day>|all] [--part <1|2>] [--answers <path>] [<inputs>]
+       aoc bench [<day>|all] [--part <1|2>] [--runs <n>] [--save <path>] [--baseline <path>] [<inputs>]
+       aoc debug [--part <1|2>] [--input <path>] [<inputs>]
+
+Inputs: [--inputs <dir>] [--profile <name>]
+
+Solve the puzzle of a given day (1 to 25), or of every day. By default, both parts are solved,
+using the puzzle input found in `<dir>/day<day>.txt`, and the answers are printed as text.
+The input of a single day can also be given as a file, read from the standard input (`--input -`)
+or given on the command line (--input-str).
+
+The inputs directory is given by --inputs or the AOC_INPUTS environment variable, and defaults to
+`inputs` (in the working directory if it exists, or else in the crate). To keep the inputs of
+several people side by side, store them in `<dir>/<profile>/day<day>.txt` and select the profile
+with --profile or the AOC_PROFILE environment variable.
+
+The spells of day 22 are read from `day22_spells.toml` next to the inputs when the file exists, to
+try other spells than those of the puzzle.
+
+The `verify` command compares the answers to the expected ones, read from `answers.toml` next to
+the inputs by default, and fails if any of them doesn't match.
+
+The `bench` command solves each part several times (5 by default) and prints the minimum, median
+and maximum times. The timings can be saved to a file, and later used as a baseline to compare the
+medians against.
+
+The `debug` command runs the program of day 23 in a debugger, which reads commands from the standard
+input (type `help` to list them). Part 2 starts with 1 in register `a`.";
+
+/// Instructions executed by `continue` at most, unless given another number.
+const DEBUG_MAX_STEPS: usize = 1_000_000;
+
+const DEBUG_HELP: &str = "\
+step [<n>]          Execute n instructions (1 by default).
+continue [<n>]      Run until a breakpoint, a watched register changes, the program finishes or
+                    loops, or n instructions (1000000 by default) are executed.
+break [<index>]     Stop before the instruction at the index, or list the breakpoints.
+delete <index>      Remove a breakpoint.
+watch [<register>]  Stop when the register changes, or list the watched registers.
+unwatch <register>  Stop watching a register.
+trace [on|off|<n>]  Start or stop tracing, or print the last n (10) traced instructions.
+registers           Print the instruction pointer and the registers.
+list                Print the program.
+analyze [dot]       Print the basic blocks of the program, its loops and the idioms recognized in
+                    them, or its control-flow graph in the DOT language of Graphviz.
+quit                Leave the debugger.";
+
+/// Input given on the command line, instead of the one of the inputs directory.
+enum Source {
+    File(String),
+    Stdin,
+    Text(String),
+}
+
+/// Options of the `run`, `verify` and `bench` commands.
+struct Options {
+    days: Vec<usize>,
+    part: Option<Part>,
+    input: Option<Source>,
+    answers: Option<String>,
+    format: Format,
+    runs: usize,
+    save: Option<String>,
+    baseline: Option<String>,
+    inputs: Inputs,
+    spells: Option<CustomSpells>,
+}
+
+impl Options {
+    fn from_args(command: &str, args: &[String]) -> Result<Options, String> {
+        let mut iter = args.iter().peekable();
+
+        /* The day is only required to solve puzzles. */
+        let day = match iter.peek() {
+            Some(arg) if command != "debug" && !arg.starts_with("--") => iter.next().unwrap().as_str(),
+            _ if command == "debug" => "23",
+            _ if command != "run" => "all",
+            _ => return Err("Missing day.".to_string()),
+        };
+        let days = match day {
+            "all" => (1..=NBR_DAYS).collect(),
+            _ => match day.parse() {
+                Ok(x) if (1..=NBR_DAYS).contains(&x) => vec![x],
+                _ => return Err(format!("Invalid day: {}.", day)),
+            },
+        };
+
+        let mut options = Options {
+            days,
+            part: None,
+            input: None,
+            answers: None,
+            format: Format::Text,
+            runs: 5,
+            save: None,
+            baseline: None,
+            inputs: Inputs::resolve(None, None),
+            spells: None,
+        };
+        let (mut dir, mut profile) = (None, None);
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let part = iter.next().ok_or("Missing part.")?;
+                    options.part = part.parse().ok().and_then(Part::from_number);
+                    if options.part.is_none() {
+                        return Err(format!("Invalid part: {}.", part));
+                    }
+                },
+                "--input" if command == "run" || command == "debug" => {
+                    let input = iter.next().ok_or("Missing input path.")?;
+                    options.input = Some(match input.as_str() {
+                        /* The debugger reads its commands from the standard input. */
+                        "-" if command == "debug" =>
+                            return Err("The program can't be read from the standard input.".to_string()),
+                        "-" => Source::Stdin,
+                        _ => Source::File(input.to_string()),
+                    });
+                },
+                "--input-str" if command == "run" => {
+                    let input = iter.next().ok_or("Missing input.")?;
+                    options.input = Some(Source::Text(input.to_string()));
+                },
+                "--answers" if command == "verify" => {
+                    let answers = iter.next().ok_or("Missing answers path.")?;
+                    options.answers = Some(answers.to_string());
+                },
+                "--format" if command == "run" => {
+                    let format = iter.next().ok_or("Missing format.")?;
+                    options.format = format.parse()?;
+                },
+                "--runs" if command == "bench" => {
+                    let runs = iter.next().ok_or("Missing number of runs.")?;
+                    options.runs = match runs.parse() {
+                        Ok(x) if x > 0 => x,
+                        _ => return Err(format!("Invalid number of runs: {}.", runs)),
+                    };
+                },
+                "--save" if command == "bench" => {
+                    let save = iter.next().ok_or("Missing path of the timings.")?;
+                    options.save = Some(save.to_string());
+                },
+                "--baseline" if command == "bench" => {
+                    let baseline = iter.next().ok_or("Missing baseline path.")?;
+                    options.baseline = Some(baseline.to_string());
+                },
+                "--inputs" => {
+                    dir = Some(iter.next().ok_or("Missing inputs directory.")?.as_str());
+                },
+                "--profile" => {
+                    profile = Some(iter.next().ok_or("Missing profile.")?.as_str());
+                },
+                _ => return Err(format!("Unknown argument: {}.", arg)),
+            }
+        }
+
+        options.inputs = Inputs::resolve(dir, profile);
+
+        if options.days.contains(&22) {
+            let path = options.inputs.spells_path();
+            if let Ok(contents) = fs::read_to_string(&path) {
+                let spells = day22::read_spells(&contents)
+                    .map_err(|e| e.in_file(&path.display().to_string()).to_string())?;
+                options.spells = Some(CustomSpells(spells));
+            }
+        }
+
+        if options.input.is_some() && options.days.len() > 1 {
+            return Err("An input can only be given for a single day.".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+impl Options {
+    /// Solver of the day, casting the spells of the inputs directory on day 22 if there are any.
+    fn solver(&self, day: usize) -> Option<&dyn Solver> {
+        match (day, &self.spells) {
+            (22, Some(spells)) => Some(spells),
+            _ => solver(day),
+        }
+    }
+
+    /// Read the puzzle input of the day, along with the name of its file.
+    fn read_input(&self, day: usize) -> Result<(String, String), Box<dyn Error>> {
+        match &self.input {
+            Some(Source::File(path)) => Ok((path.clone(), inputs::read_input(Path::new(path), None, None)?)),
+            Some(Source::Stdin) => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(("<stdin>".to_string(), input))
+            },
+            Some(Source::Text(input)) => Ok(("<input>".to_string(), input.clone())),
+            None => Ok((self.inputs.path(day).display().to_string(), self.inputs.read(day)?)),
+        }
+    }
+
+    /// Parts to solve for the day.
+    fn parts(&self, solver: &dyn Solver) -> Vec<Part> {
+        /* When running every day, skip the missing parts instead of failing. */
+        match self.part {
+            Some(part) if self.days.len() > 1 && !solver.parts().contains(&part) => vec![],
+            Some(part) => vec![part],
+            None => solver.parts().to_vec(),
+        }
+    }
+}
+
+fn run(options: &Options) -> Result<(), Box<dyn Error>> {
+    let mut answers = Vec::new();
+
+    for &day in &options.days {
+        let solver = options.solver(day).ok_or("No solver for this day.")?;
+        let (filename, input) = options.read_input(day)?;
+
+        for part in options.parts(solver) {
+            answers.push(Answer::solve(day, solver, part, &input).map_err(|e| locate(e, &filename))?);
+        }
+    }
+
+    print!("{}", options.format.format(&answers));
+    Ok(())
+}
+
+/* Solve the puzzles and compare the answers to the expected ones. Returns
+ * whether every answer matched (or was missing from the file). */
+fn verify(options: &Options) -> Result<bool, Box<dyn Error>> {
+    let filename = match &options.answers {
+        Some(path) => path.clone(),
+        None => options.inputs.answers_path().display().to_string(),
+    };
+    let contents = fs::read_to_string(&filename).map_err(|e| format!("{}: {}", filename, e))?;
+    let expected = ExpectedAnswers::from_toml(&contents).map_err(|e| e.in_file(&filename))?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for &day in &options.days {
+        let solver = options.solver(day).ok_or("No solver for this day.")?;
+        let (filename, input) = match options.read_input(day) {
+            Ok(input) => input,
+            Err(e) => {
+                for part in options.parts(solver) {
+                    failed += 1;
+                    println!("Day {}, part {}: FAIL ({})", day, part, e);
+                }
+                continue;
+            },
+        };
+
+        for part in options.parts(solver) {
+            let answer = Answer::solve(day, solver, part, &input).map_err(|e| locate(e, &filename));
+
+            print!("Day {}, part {}: ", day, part);
+            match answer.map(|answer| (expected.check(&answer), answer)) {
+                Ok((Verdict::Pass, answer)) => {
+                    passed += 1;
+                    println!("pass ({})", answer.value);
+                },
+                Ok((Verdict::Fail(value), answer)) => {
+                    failed += 1;
+                    println!("FAIL (expected {}, found {})", value, answer.value);
+                },
+                Ok((Verdict::Missing, answer)) => {
+                    missing += 1;
+                    println!("missing (found {})", answer.value);
+                },
+                Err(e) => {
+                    failed += 1;
+                    println!("FAIL ({})", e);
+                },
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed, {} missing.", passed, failed, missing);
+    Ok(failed == 0)
+}
+
+fn bench(options: &Options) -> Result<(), Box<dyn Error>> {
+    /* Read the baseline first, so that a typo in its path doesn't waste a full
+     * benchmark. */
+    let baseline = match &options.baseline {
+        Some(filename) => Some(Baseline::from_tsv(&fs::read_to_string(filename)?)
+            .map_err(|e| e.in_file(filename))?),
+        None => None,
+    };
+
+    let mut timings = Vec::new();
+
+    for &day in &options.days {
+        let solver = options.solver(day).ok_or("No solver for this day.")?;
+        let (filename, input) = options.read_input(day)?;
+
+        for part in options.parts(solver) {
+            timings.push(Timings::measure(day, solver, part, &input, options.runs)
+                .map_err(|e| locate(e, &filename))?);
+        }
+    }
+
+    print!("{}", bench::to_table(&timings, baseline.as_ref()));
+
+    if let Some(filename) = &options.save {
+        fs::write(filename, bench::to_tsv(&timings))?;
+    }
+
+    Ok(())
+}
+
+fn debug(options: &Options) -> Result<(), Box<dyn Error>> {
+    let (filename, input) = options.read_input(23)?;
+    let mut computer = Computer::new();
+    computer.load_program(&input).map_err(|e| e.in_file(&filename))?;
+    if options.part == Some(Part::Two) {
+        computer.set_register(0, 1);
+    }
+
+    let mut debugger = Debugger::new(computer);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    print_position(&debugger);
+    loop {
+        print!("(debug) ");
+        io::stdout().flush()?;
+
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+
+        match debug_command(&mut debugger, &line) {
+            Ok(true) => (),
+            Ok(false) => break,
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    Ok(())
+}
+
+/* Execute a command of the debugger. Returns whether to keep debugging. */
+fn debug_command(debugger: &mut Debugger, line: &str) -> Result<bool, String> {
+    let mut iter = line.split_whitespace();
+    let command = match iter.next() {
+        Some(command) => command,
+        None => return Ok(true),
+    };
+    let argument = iter.next();
+
+    let index = |argument: Option<&str>| -> Result<usize, String> {
+        let argument = argument.ok_or("Missing instruction index.")?;
+        argument.parse().map_err(|_| format!("Invalid instruction index: {}.", argument))
+    };
+    let registers = debugger.computer.registers.len();
+    let register = |argument: Option<&str>| -> Result<usize, String> {
+        let argument = argument.ok_or("Missing register.")?;
+        day23::register(argument)
+            .filter(|&r| r < registers)
+            .ok_or_else(|| format!("Invalid register: {}.", argument))
+    };
+
+    match command {
+        "step" | "s" => {
+            let n = match argument {
+                Some(n) => n.parse().map_err(|_| format!("Invalid number of steps: {}.", n))?,
+                None => 1,
+            };
+            let traced = debugger.trace().len();
+            let mut stop = Stop::Step;
+            for _ in 0..n {
+                stop = debugger.step();
+                if stop != Stop::Step {
+                    break;
+                }
+            }
+            print_stop(debugger, traced, stop);
+        },
+        "continue" | "c" => {
+            let n = match argument {
+                Some(n) => n.parse().map_err(|_| format!("Invalid number of steps: {}.", n))?,
+                None => DEBUG_MAX_STEPS,
+            };
+            let traced = debugger.trace().len();
+            let stop = debugger.run(&Limits { max_steps: Some(n), detect_loops: true });
+            print_stop(debugger, traced, stop);
+        },
+        "break" | "b" if argument.is_none() => {
+            let breakpoints: Vec<String> = debugger.breakpoints().map(|ip| ip.to_string()).collect();
+            println!("Breakpoints: {}", breakpoints.join(", "));
+        },
+        "break" | "b" => {
+            debugger.set_breakpoint(index(argument)?);
+        },
+        "delete" | "d" => {
+            if !debugger.clear_breakpoint(index(argument)?) {
+                return Err("No breakpoint there.".to_string());
+            }
+        },
+        "watch" | "w" if argument.is_none() => {
+            let watchpoints: Vec<String> = debugger.watchpoints()
+                .map(|r| day23::register_name(r).to_string())
+                .collect();
+            println!("Watched registers: {}", watchpoints.join(", "));
+        },
+        "watch" | "w" => {
+            debugger.watch(register(argument)?);
+        },
+        "unwatch" => {
+            if !debugger.unwatch(register(argument)?) {
+                return Err("This register isn't watched.".to_string());
+            }
+        },
+        "trace" | "t" => match argument {
+            Some("on") => debugger.set_tracing(true),
+            Some("off") => debugger.set_tracing(false),
+            _ => {
+                let n = match argument {
+                    Some(n) => n.parse().map_err(|_| format!("Invalid number of instructions: {}.", n))?,
+                    None => 10,
+                };
+                let trace = debugger.trace();
+                for entry in &trace[trace.len().saturating_sub(n)..] {
+                    println!("{}", entry);
+                }
+            },
+        },
+        "registers" | "r" => print_position(debugger),
+        "list" | "l" => {
+            for (idx, instruction) in debugger.computer.memory.iter().enumerate() {
+                let marker = if idx == debugger.computer.instruction_pointer { '>' } else { ' ' };
+                println!("{}{:>3}  {}", marker, idx, instruction);
+            }
+        },
+        "analyze" | "a" => {
+            let graph = ControlFlowGraph::new(&debugger.computer.memory);
+            match argument {
+                Some("dot") => print!("{}", graph.to_dot()),
+                Some(argument) => return Err(format!("Unknown argument: {}.", argument)),
+                None => {
+                    print!("{}", graph);
+                    for idiom in graph.idioms() {
+                        println!("{}", idiom);
+                    }
+                },
+            }
+        },
+        "help" | "h" => println!("{}", DEBUG_HELP),
+        "quit" | "q" => return Ok(false),
+        _ => return Err(format!("Unknown command: {} (type `help` to list them).", command)),
+    }
+
+    Ok(true)
+}
+
+/* Print the instructions traced since `traced`, why the debugger stopped and where. */
+fn print_stop(debugger: &Debugger, traced: usize, stop: Stop) {
+    for entry in debugger.trace().iter().skip(traced) {
+        println!("{}", entry);
+    }
+
+    match stop {
+        Stop::Step => (),
+        Stop::Halted(halt) => println!("{}", halt),
+        Stop::Breakpoint(ip) => println!("Breakpoint at {}.", ip),
+        Stop::Watchpoint { register, old, new } =>
+            println!("Register {} changed from {} to {}.", day23::register_name(register), old, new),
+    }
+
+    print_position(debugger);
+}
+
+/* Print the next instruction with the registers, as in the trace. */
+fn print_position(debugger: &Debugger) {
+    let computer = &debugger.computer;
+    let instruction = match computer.memory.get(computer.instruction_pointer) {
+        Some(instruction) => instruction.to_string(),
+        None => "(end)".to_string(),
+    };
+    let registers: String = computer.registers.iter().enumerate()
+        .map(|(register, value)| format!("  {}={}", day23::register_name(register), value))
+        .collect();
+
+    println!("{:>4}  {:<12}{}", computer.instruction_pointer, instruction, registers);
+}
+
+/* Add the name of the file to parse errors, to point to the offending token. */
+fn locate(error: Box<dyn Error>, filename: &str) -> Box<dyn Error> {
+    match error.downcast::<ParseError>() {
+        Ok(e) => Box::new(e.in_file(filename)),
+        Err(e) => e,
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => Options::from_args("run", &args[1..])
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|options| run(&options)),
+        Some("verify") => Options::from_args("verify", &args[1..])
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|options| verify(&options))
+            .map(|success| if !success { process::exit(1) }),
+        Some("bench") => Options::from_args("bench", &args[1..])
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|options| bench(&options)),
+        Some("debug") => Options::from_args("debug", &args[1..])
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|options| debug(&options)),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        },
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,80 @@
+//! Day 1: Not Quite Lisp (<https://adventofcode.com/2015/day/1>).
+
+use crate::solver::{Result, Solver};
+
+/// Santa is trying to deliver presents in a large apartment building, but he can't find the
+/// right  floor - the directions he got are a little confusing. He starts on the ground floor
+/// (floor 0) and then follows the instructions one character at a time.
+///
+/// An opening parenthesis, (, means he should go up one floor, and a closing parenthesis, ),
+/// means he should go down one floor.
+///
+/// The apartment building is very tall, and the basement is very deep; he will never find the
+/// top or bottom floors.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(input).to_string())
+    }
+}
+
+/// To what floor do the instructions take Santa?
+pub fn part1(instructions: &str) -> i32 {
+    instructions.chars().filter_map(convert_parentheses).sum()
+}
+
+/// Now, given the same instructions, find the position of the first character that causes him to
+/// enter the basement (floor -1). The first character in the instructions has position 1, the
+/// second character has position 2, and so on.
+pub fn part2(instructions: &str) -> usize {
+    instructions
+        .chars()
+        .filter_map(convert_parentheses)
+        .scan(0, |state, x| {
+            *state += x;
+            Some(*state)
+        })
+        .position(|x| x == -1)
+        .unwrap_or(0)
+        // Convert to 1-indexing.
+        + 1
+}
+
+/// Convert opening and closing parentheses to an up (+1) or down (-1) value, returning a None for
+/// other characters.
+pub fn convert_parentheses(c: char) -> Option<i32> {
+    match c {
+        '(' => Some(1),
+        ')' => Some(-1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn final_floor() {
+        assert_eq!(part1("(())"), 0);
+        assert_eq!(part1("()()"), 0);
+        assert_eq!(part1("((("), 3);
+        assert_eq!(part1("(()(()("), 3);
+        assert_eq!(part1("))((((("), 3);
+        assert_eq!(part1("())"), -1);
+        assert_eq!(part1("))("), -1);
+        assert_eq!(part1(")))"), -3);
+        assert_eq!(part1(")())())"), -3);
+    }
+
+    #[test]
+    fn basement_position() {
+        assert_eq!(part2(")"), 1);
+        assert_eq!(part2("()())"), 5);
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 2: I Was Told There Would Be No Math (<https://adventofcode.com/2015/day/2>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::convert::TryFrom;
+use std::iter;
+
+/// The elves are running low on wrapping paper, and so they need to submit an order for more.
+/// They have a list of the dimensions (length l, width w, and height h) of each present, and
+/// only want to order exactly as much as they need.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(&read_presents(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(&read_presents(input)?).to_string())
+    }
+}
+
+/// Presents are perfect right rectangular prisms, with fields `length`, `width` and `height`.
+pub struct Present {
+    /// Length of the present, in feet.
+    pub length: u32,
+    /// Width of the present, in feet.
+    pub width: u32,
+    /// Height of the present, in feet.
+    pub height: u32,
+}
+
+impl Present {
+    /// Create an iterator over the sides.
+    pub fn sides(&self) -> impl Iterator<Item = u32> {
+        iter::once(self.length).chain(iter::once(self.width).chain(iter::once(self.height)))
+    }
+
+    /// Compute the surface area of a present.
+    pub fn surface_area(&self) -> u32 {
+        2 * (self.length * self.width + self.length * self.height + self.width * self.height)
+    }
+
+    /// Compute the surface area of the smallest side.
+    pub fn smallest_side_area(&self) -> u32 {
+        /* Dividing the volume by the longest side fails on flat presents. */
+        let mut sides: Vec<u32> = self.sides().collect();
+        sides.sort_unstable();
+        sides[0] * sides[1]
+    }
+
+    /// Compute the minimal amount of wrapping paper required to wrap a present, which is the
+    /// surface area of the present plus the area of the smallest side (a bit extra).
+    pub fn wrapping_paper(&self) -> u32 {
+        self.surface_area() + self.smallest_side_area()
+    }
+
+    /// Compute the volume of the present.
+    pub fn volume(&self) -> u32 {
+        self.sides().product()
+    }
+
+    /// Compute the minimal amount of ribbon required to wrap a present, which is the shortest
+    /// distance around its sides, plus a bow whose length is equal to the volume of the present.
+    pub fn ribbon(&self) -> u32 {
+        let d: u32 = self.sides().sum::<u32>() - self.sides().max().unwrap();
+        2 * d + self.volume()
+    }
+}
+
+impl TryFrom<&str> for Present {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> std::result::Result<Self, Self::Error> {
+        let mut dims = input.split('x');
+
+        Ok(Present {
+            length: parse_token(input, next_token(&mut dims, input, "length")?)?,
+            width: parse_token(input, next_token(&mut dims, input, "width")?)?,
+            height: parse_token(input, next_token(&mut dims, input, "height")?)?,
+        })
+    }
+}
+
+/// Read the list of presents, one per line (e.g. `2x3x4`).
+pub fn read_presents(input: &str) -> std::result::Result<Vec<Present>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, line)| Present::try_from(line).map_err(|e| e.at_line(idx + 1)))
+        .collect()
+}
+
+/// All numbers in the elves' list are in feet. How many total square feet of wrapping paper
+/// should they order?
+pub fn part1(presents: &[Present]) -> u32 {
+    presents.iter().map(Present::wrapping_paper).sum()
+}
+
+/// How many total feet of ribbon should they order?
+pub fn part2(presents: &[Present]) -> u32 {
+    presents.iter().map(Present::ribbon).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn wrapping_paper() {
+        let presents = read_presents("2x3x4\n1x1x10\n").unwrap();
+        assert_eq!(presents[0].wrapping_paper(), 58);
+        assert_eq!(presents[1].wrapping_paper(), 43);
+        assert_eq!(part1(&presents), 101);
+    }
+
+    #[test]
+    fn ribbon() {
+        let presents = read_presents("2x3x4\n1x1x10\n").unwrap();
+        assert_eq!(presents[0].ribbon(), 34);
+        assert_eq!(presents[1].ribbon(), 14);
+        assert_eq!(part2(&presents), 48);
+    }
+
+    proptest! {
+        #[test]
+        fn ribbon_wraps_smallest_perimeter(length in 0..1000u32, width in 0..1000u32, height in 0..1000u32) {
+            let present = Present { length, width, height };
+            let perimeters = [2 * (length + width), 2 * (length + height), 2 * (width + height)];
+            let smallest_perimeter = *perimeters.iter().min().unwrap();
+
+            prop_assert!(present.ribbon() >= smallest_perimeter);
+            prop_assert_eq!(present.ribbon(), smallest_perimeter + present.volume());
+        }
+
+        #[test]
+        fn paper_covers_surface(length in 0..1000u32, width in 0..1000u32, height in 0..1000u32) {
+            let present = Present { length, width, height };
+            let rotated = Present { length: height, width: length, height: width };
+
+            prop_assert!(present.wrapping_paper() >= present.surface_area());
+            prop_assert!(6 * present.smallest_side_area() <= present.surface_area());
+            prop_assert_eq!(present.wrapping_paper(), rotated.wrapping_paper());
+            prop_assert_eq!(present.ribbon(), rotated.ribbon());
+        }
+    }
+}
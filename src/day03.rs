@@ -0,0 +1,106 @@
+//! Day 3: Perfectly Spherical Houses in a Vacuum (<https://adventofcode.com/2015/day/3>).
+
+use crate::solver::{Result, Solver};
+use std::collections::HashSet;
+
+/// Santa is delivering presents to an infinite two-dimensional grid of houses, moving one house
+/// to the north (^), south (v), east (>) or west (<) after each delivery.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(input).to_string())
+    }
+}
+
+/// Position of a house on the grid.
+#[derive(PartialEq, Eq, Hash)]
+pub struct Position {
+    /// Position to the east (negative to the west).
+    pub x: i32,
+    /// Position to the north (negative to the south).
+    pub y: i32,
+}
+
+impl Position {
+    /// Create a position from its coordinates.
+    pub fn new(x: i32, y: i32) -> Position {
+        Position{x, y}
+    }
+
+    /// Move to the next house, following a direction (^, v, < or >). Other characters are ignored.
+    pub fn update(&mut self, direction: char) {
+        match direction {
+            '^' => self.y += 1,
+            'v' => self.y -= 1,
+            '<' => self.x -= 1,
+            '>' => self.x += 1,
+            _ => (),
+        }
+    }
+
+    /// Coordinates of the position, as `(x, y)`.
+    pub fn as_tuple(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
+/// How many houses receive at least one present?
+pub fn part1(directions: &str) -> usize {
+    let mut visited_houses: HashSet<(i32, i32)> = HashSet::new();
+
+    let mut position = Position::new(0, 0);
+    visited_houses.insert(position.as_tuple());
+
+    directions
+        .chars()
+        .for_each(|c| {position.update(c); visited_houses.insert(position.as_tuple());});
+
+    visited_houses.len()
+}
+
+/// Santa and Robo-Santa take turns following the directions. How many houses receive at least
+/// one present?
+pub fn part2(directions: &str) -> usize {
+    let mut visited_houses: HashSet<(i32, i32)> = HashSet::new();
+
+    let mut santa_position = Position::new(0, 0);
+    let mut robosanta_position = Position::new(0, 0);
+    visited_houses.insert((0, 0));
+
+    directions
+        .chars()
+        .step_by(2)
+        .for_each(|c| {santa_position.update(c); visited_houses.insert(santa_position.as_tuple());});
+
+    directions
+        .chars()
+        .skip(1)
+        .step_by(2)
+        .for_each(|c| {robosanta_position.update(c); visited_houses.insert(robosanta_position.as_tuple());});
+
+    visited_houses.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn santa_alone() {
+        assert_eq!(part1(">"), 2);
+        assert_eq!(part1("^>v<"), 4);
+        assert_eq!(part1("^v^v^v^v^v"), 2);
+    }
+
+    #[test]
+    fn santa_and_robo_santa() {
+        assert_eq!(part2("^v"), 3);
+        assert_eq!(part2("^>v<"), 3);
+        assert_eq!(part2("^v^v^v^v^v"), 11);
+    }
+}
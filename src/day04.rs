@@ -0,0 +1,41 @@
+//! Day 4: The Ideal Stocking Stuffer (<https://adventofcode.com/2015/day/4>).
+
+use crate::solver::{Result, Solver};
+
+/// Santa needs help mining some AdventCoins, by finding MD5 hashes which, in hexadecimal, start
+/// with at least five zeroes.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        mine(input.trim(), "00000")
+            .map(|x| x.to_string())
+            .ok_or_else(|| "No suitable number found.".into())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        mine(input.trim(), "000000")
+            .map(|x| x.to_string())
+            .ok_or_else(|| "No suitable number found.".into())
+    }
+}
+
+/// Find the lowest positive number which, appended to the secret key, produces an MD5 hash
+/// starting with `difficulty`.
+pub fn mine(secret_key: &str, difficulty: &str) -> Option<u32> {
+    (0..u32::MAX)
+        .map(|c| (c, md5::compute(format!("{}{}", secret_key, c))))
+        .find(|(_c, digest)| format!("{:x}", digest).starts_with(difficulty))
+        .map(|(x, _)| x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn five_zeroes() {
+        assert_eq!(mine("abcdef", "00000"), Some(609043));
+        assert_eq!(mine("pqrstuv", "00000"), Some(1048970));
+    }
+}
@@ -0,0 +1,99 @@
+//! Day 5: Doesn't He Have Intern-Elves For This? (<https://adventofcode.com/2015/day/5>).
+
+use crate::error::ParseError;
+use crate::solver::{Result, Solver};
+
+/// Santa needs help figuring out which strings in his text file are naughty or nice.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        check_input(input)?;
+        Ok(part1(input).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        check_input(input)?;
+        Ok(part2(input).to_string())
+    }
+}
+
+/// Check that the text file has no blank line.
+pub fn check_input(contents: &str) -> std::result::Result<(), ParseError> {
+    match contents.lines().position(|line| line.is_empty()) {
+        Some(idx) => Err(ParseError::missing("", "Missing string.").at_line(idx + 1)),
+        None => Ok(()),
+    }
+}
+
+/// Count the nice strings: at least three vowels, one letter appearing twice in a row, and none
+/// of the strings `ab`, `cd`, `pq` or `xy`.
+pub fn part1(contents: &str) -> usize {
+    contents.lines()
+        .filter(|line| !contains_bad_strings(line)
+            && double_letter(line)
+            && (vowel_count(line) >= 3)
+        )
+        .count()
+}
+
+/// Count the vowels (aeiou) in a string.
+pub fn vowel_count(s: &str) -> usize {
+    s.chars()
+        .filter(|&c| c == 'a' || c == 'e' || c == 'i' || c == 'o' || c == 'u')
+        .count()
+}
+
+/// Check whether a letter appears twice in a row.
+pub fn double_letter(s: &str) -> bool {
+    s.as_bytes().windows(2).any(|window| window[0] == window[1])
+}
+
+/// Check for the forbidden strings `ab`, `cd`, `pq` and `xy`.
+pub fn contains_bad_strings(s: &str) -> bool {
+    s.contains("ab") || s.contains("cd") || s.contains("pq") || s.contains("xy")
+}
+
+/// Count the nice strings, according to the new rules: a pair of letters appearing twice without
+/// overlapping, and a letter which repeats with exactly one letter between them.
+pub fn part2(contents: &str) -> usize {
+    contents.lines()
+        .filter(|line| repeating_pair(line) && repeating_letter(line))
+        .count()
+}
+
+/// Check for a pair of letters appearing at least twice, without overlapping.
+pub fn repeating_pair(s: &str) -> bool {
+    let s = s.as_bytes();
+    (0..s.len().saturating_sub(1)).any(|idx| s[idx+2..].windows(2).any(|pair| pair == &s[idx..idx+2]))
+}
+
+/// Check for a letter which repeats with exactly one letter between them (e.g. `xyx`).
+pub fn repeating_letter(s: &str) -> bool {
+    s.as_bytes().windows(3).any(|window| window[0] == window[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nice_strings() {
+        assert_eq!(part1("ugknbfddgicrmopn"), 1);
+        assert_eq!(part1("aaa"), 1);
+        assert_eq!(part1("jchzalrnumimnmhp"), 0);
+        assert_eq!(part1("haegwjzuvuyypxyu"), 0);
+        assert_eq!(part1("dvszwmarrgswjxmb"), 0);
+    }
+
+    #[test]
+    fn nice_strings_new_rules() {
+        assert_eq!(part2("qjhvhtzxzqqjkmpb"), 1);
+        assert_eq!(part2("xxyxx"), 1);
+        assert_eq!(part2("uurcxstgmygtbstg"), 0);
+        assert_eq!(part2("ieodomkazucvgmuy"), 0);
+        assert!(!repeating_pair("aaa"));
+        assert!(!repeating_pair("") && !repeating_letter("a"));
+        assert_eq!(check_input("xxyxx\n\nxyx\n").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,212 @@
+//! Day 6: Probably a Fire Hazard (<https://adventofcode.com/2015/day/6>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+
+/// Santa wants to deploy a million lights in a 1000x1000 grid, following a set of instructions
+/// to turn on, turn off or toggle rectangular ranges of lights.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+/// Position of a light on the grid.
+pub type Coordinates = (usize, usize);
+
+/// Instructions given to the lights.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Turn the lights on (or increase their brightness by 1).
+    TurnOn,
+    /// Turn the lights off (or decrease their brightness by 1, down to 0).
+    TurnOff,
+    /// Switch the lights (or increase their brightness by 2).
+    Toggle,
+}
+
+/// Grid of lights which are either on or off.
+pub struct LightGrid (Vec<[bool; 1000]>);
+
+impl LightGrid {
+    /// Create a grid with all lights off.
+    pub fn new() -> LightGrid {
+        LightGrid(vec![[false; 1000]; 1000])
+    }
+
+    /// Turn on every light from `start` to `end` (inclusive).
+    pub fn turn_on(&mut self, start: &(usize, usize), end: &(usize, usize)) {
+        (start.0..end.0+1)
+            .for_each(|x|
+                (start.1..end.1+1).for_each(|y| self.0[x][y] = true)
+            );
+    }
+
+    /// Turn off every light from `start` to `end` (inclusive).
+    pub fn turn_off(&mut self, start: &(usize, usize), end: &(usize, usize)) {
+        (start.0..end.0+1)
+            .for_each(|x|
+                (start.1..end.1+1).for_each(|y| self.0[x][y] = false)
+            );
+    }
+
+    /// Toggle every light from `start` to `end` (inclusive).
+    pub fn toggle(&mut self, start: &(usize, usize), end: &(usize, usize)) {
+        (start.0..end.0+1)
+            .for_each(|x|
+                (start.1..end.1+1).for_each(|y| self.0[x][y] ^= true)
+            );
+    }
+
+    /// Count the lights which are on.
+    pub fn nbr_lights_on(&self) -> u32 {
+        self.0.iter().
+            map(|line| line.iter().map(|&x| x as u32).sum::<u32>())
+            .sum()
+    }
+}
+
+impl Default for LightGrid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Grid of lights with individual brightness controls (Ancient Nordic Elvish).
+pub struct LightgridPart2 (Vec<[u32; 1000]>);
+
+impl LightgridPart2 {
+    /// Create a grid with all lights at zero brightness.
+    pub fn new() -> LightgridPart2 {
+        LightgridPart2(vec![[0; 1000]; 1000])
+    }
+
+    /// Increase the brightness by 1 from `start` to `end` (inclusive).
+    pub fn turn_on(&mut self, start: &(usize, usize), end: &(usize, usize)) {
+        (start.0..end.0+1)
+            .for_each(|x|
+                (start.1..end.1+1).for_each(|y| self.0[x][y] += 1)
+            );
+    }
+
+    /// Decrease the brightness by 1 (to a minimum of 0) from `start` to `end` (inclusive).
+    pub fn turn_off(&mut self, start: &(usize, usize), end: &(usize, usize)) {
+        (start.0..end.0+1)
+            .for_each(|x|
+                (start.1..end.1+1).for_each(
+                    |y| self.0[x][y] = self.0[x][y].saturating_sub(1)
+                )
+            );
+    }
+
+    /// Increase the brightness by 2 from `start` to `end` (inclusive).
+    pub fn toggle(&mut self, start: &(usize, usize), end: &(usize, usize)) {
+        (start.0..end.0+1)
+            .for_each(|x|
+                (start.1..end.1+1).for_each(|y| self.0[x][y] += 2)
+            );
+    }
+
+    /// Total brightness of the lights.
+    pub fn nbr_lights_on(&self) -> u32 {
+        self.0.iter().flat_map(|line| line.iter()).sum()
+    }
+}
+
+impl Default for LightgridPart2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Read an instruction (e.g. `turn on 0,0 through 999,999`), returning the action with the start
+/// and end coordinates (inclusive).
+pub fn read_instruction(line: &str)
+        -> std::result::Result<(Action, Coordinates, Coordinates), ParseError> {
+    /* Keep in mind we are doing a reverse split, to get a full instruction
+     * ("toggle", "turn on", "turn off"). */
+    let mut iter = line.rsplitn(4, ' ');
+    let end_idx = read_coordinates(line, next_token(&mut iter, line, "coordinates")?)?;
+    next_token(&mut iter, line, "`through`")?;
+    let start_idx = read_coordinates(line, next_token(&mut iter, line, "coordinates")?)?;
+
+    let action = match next_token(&mut iter, line, "instruction")? {
+        "turn on" => Action::TurnOn,
+        "turn off" => Action::TurnOff,
+        "toggle" => Action::Toggle,
+        s => return Err(ParseError::new(line, s, "Unknown instruction.")),
+    };
+
+    Ok((action, start_idx, end_idx))
+}
+
+/* Coordinates (e.g. `0,999`) must be within the grid. */
+fn read_coordinates(line: &str, token: &str) -> std::result::Result<Coordinates, ParseError> {
+    let mut iter = token.split(',');
+    let x: usize = parse_token(line, next_token(&mut iter, line, "x coordinate")?)?;
+    let y: usize = parse_token(line, next_token(&mut iter, line, "y coordinate")?)?;
+
+    if x >= 1000 || y >= 1000 {
+        return Err(ParseError::new(line, token, "Coordinates outside of the grid."));
+    }
+
+    Ok((x, y))
+}
+
+/// After following the instructions, how many lights are lit?
+pub fn part1(contents: &str) -> std::result::Result<u32, ParseError> {
+    let mut light_grid = LightGrid::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let (action, start_idx, end_idx) = read_instruction(line).map_err(|e| e.at_line(idx + 1))?;
+
+        match action {
+            Action::TurnOff => light_grid.turn_off(&start_idx, &end_idx),
+            Action::TurnOn => light_grid.turn_on(&start_idx, &end_idx),
+            Action::Toggle => light_grid.toggle(&start_idx, &end_idx),
+        }
+    }
+
+    Ok(light_grid.nbr_lights_on())
+}
+
+/// After following the instructions, what is the total brightness of all lights combined?
+pub fn part2(contents: &str) -> std::result::Result<u32, ParseError> {
+    let mut light_grid = LightgridPart2::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let (action, start_idx, end_idx) = read_instruction(line).map_err(|e| e.at_line(idx + 1))?;
+
+        match action {
+            Action::TurnOff => light_grid.turn_off(&start_idx, &end_idx),
+            Action::TurnOn => light_grid.turn_on(&start_idx, &end_idx),
+            Action::Toggle => light_grid.toggle(&start_idx, &end_idx),
+        }
+    }
+
+    Ok(light_grid.nbr_lights_on())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lights_on() {
+        assert_eq!(part1("turn on 0,0 through 999,999").unwrap(), 1_000_000);
+        assert_eq!(part1("toggle 0,0 through 999,0").unwrap(), 1000);
+        assert_eq!(part1("turn on 0,0 through 999,999\nturn off 499,499 through 500,500").unwrap(), 999_996);
+    }
+
+    #[test]
+    fn brightness() {
+        assert_eq!(part2("turn on 0,0 through 0,0").unwrap(), 1);
+        assert_eq!(part2("toggle 0,0 through 999,999").unwrap(), 2_000_000);
+    }
+}
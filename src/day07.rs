@@ -0,0 +1,855 @@
+//! Day 7: Some Assembly Required (<https://adventofcode.com/2015/day/7>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Write};
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::str::FromStr;
+
+/// Bobby Tables assembled a circuit of wires and bitwise logic gates, where each wire carries a
+/// 16-bit signal.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let mut circuit = Circuit::new(build_circuit(input)?)?;
+        Ok(signal(&mut circuit, "a")?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let mut circuit = Circuit::new(build_circuit(input)?)?;
+        let first_answer = signal(&mut circuit, "a")?;
+
+        /* Override b with the first answer, which only invalidates the wires
+         * downstream of b. */
+        circuit.override_wire("b", first_answer).ok_or("No wire `b` in the circuit.")?;
+        Ok(signal(&mut circuit, "a")?.to_string())
+    }
+}
+
+/* Signal on a wire which may be missing from the circuit. */
+fn signal(circuit: &mut Circuit, wire: &str) -> Result<u16> {
+    circuit.signal(wire).ok_or_else(|| format!("No wire `{}` in the circuit.", wire).into())
+}
+
+/// Signal carried by the wires, which is an unsigned integer of a given width (`u16` in the
+/// puzzle, but `u8`, `u32` and `u64` are also supported).
+pub trait Word: Copy + Debug + Display + Eq + Hash + FromStr
+    + Not<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self> {
+
+    /// Signal with every bit off.
+    const ZERO: Self;
+
+    /// Shift to the left, where shifting by the width or more gives 0.
+    fn shift_left(self, amount: Self) -> Self;
+
+    /// Shift to the right, where shifting by the width or more gives 0.
+    fn shift_right(self, amount: Self) -> Self;
+
+    /// Addition, wrapping around on overflow.
+    fn add(self, other: Self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(
+            impl Word for $t {
+                const ZERO: Self = 0;
+
+                fn shift_left(self, amount: Self) -> Self {
+                    u32::try_from(amount).ok().and_then(|x| self.checked_shl(x)).unwrap_or(0)
+                }
+
+                fn shift_right(self, amount: Self) -> Self {
+                    u32::try_from(amount).ok().and_then(|x| self.checked_shr(x)).unwrap_or(0)
+                }
+
+                fn add(self, other: Self) -> Self {
+                    self.wrapping_add(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, u64);
+
+/// Input of a gate, either a constant signal or another wire.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input<T = u16> {
+    /// Constant signal.
+    Signal(T),
+    /// Signal of another wire, by name.
+    Wire(String),
+}
+
+impl<T: Word> Input<T> {
+    /// Read a signal if `s` is a number, otherwise a wire identifier.
+    pub fn new(s: &str) -> std::result::Result<Input<T>, ParseError> {
+        if s.starts_with(|c: char| c.is_ascii_digit()) {
+            Ok(Input::Signal(parse_token(s, s)?))
+        } else {
+            Ok(Input::Wire(s.to_string()))
+        }
+    }
+
+    /// Identifier of the wire, if the input isn't a constant signal.
+    pub fn wire(&self) -> Option<&str> {
+        match self {
+            Input::Signal(_) => None,
+            Input::Wire(w) => Some(w),
+        }
+    }
+}
+
+/// Logic gate connected to a wire. `Other` simply forwards its input, and `MUX(s, x, y)` (written
+/// `MUX s x y`) forwards `x` when `s` is 0, and `y` otherwise.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Gate<T = u16> {
+    /// Signal of the input.
+    Other(Input<T>),
+    /// Bitwise complement.
+    NOT(Input<T>),
+    /// Bitwise AND.
+    AND(Input<T>, Input<T>),
+    /// Bitwise OR.
+    OR(Input<T>, Input<T>),
+    /// Bitwise exclusive OR.
+    XOR(Input<T>, Input<T>),
+    /// Bitwise complement of the AND.
+    NAND(Input<T>, Input<T>),
+    /// Bitwise complement of the OR.
+    NOR(Input<T>, Input<T>),
+    /// Sum, wrapping around on overflow.
+    ADD(Input<T>, Input<T>),
+    /// Shift of the first input to the left by the second one.
+    LSHIFT(Input<T>, Input<T>),
+    /// Shift of the first input to the right by the second one.
+    RSHIFT(Input<T>, Input<T>),
+    /// Second input if the first one is 0, third input otherwise.
+    MUX(Input<T>, Input<T>, Input<T>),
+}
+
+impl<T: Word> Gate<T> {
+    /// Read the left-hand side of a connection (e.g. `x AND y`).
+    pub fn new(s: &str) -> std::result::Result<Gate<T>, ParseError> {
+        let v: Vec<&str> = s.split_whitespace().collect();
+        let input = |token: &str| Input::new(token).map_err(|e| e.within(s, token));
+
+        match v.len() {
+            0 => Err(ParseError::missing(s, "Missing gate.")),
+            1 => Ok(Gate::Other(input(v[0])?)),
+            2 => match v[0] {
+                "NOT" => Ok(Gate::NOT(input(v[1])?)),
+                _ => Err(ParseError::new(s, v[0], "Unknown gate.")),
+            },
+            3 => {
+                let constructor = match v[1] {
+                    "AND" => Gate::AND,
+                    "OR" => Gate::OR,
+                    "XOR" => Gate::XOR,
+                    "NAND" => Gate::NAND,
+                    "NOR" => Gate::NOR,
+                    "ADD" => Gate::ADD,
+                    "LSHIFT" => Gate::LSHIFT,
+                    "RSHIFT" => Gate::RSHIFT,
+                    _ => return Err(ParseError::new(s, v[1], "Unknown gate.")),
+                };
+                Ok(constructor(input(v[0])?, input(v[2])?))
+            },
+            4 if v[0] == "MUX" => Ok(Gate::MUX(input(v[1])?, input(v[2])?, input(v[3])?)),
+            /* A multiplexer has one more input than the other gates. */
+            _ if v[0] == "MUX" => Err(ParseError::new(s, v[4], "Unexpected token.")),
+            _ => Err(ParseError::new(s, v[3], "Unexpected token.")),
+        }
+    }
+
+    /// Inputs of the gate.
+    pub fn inputs(&self) -> Vec<&Input<T>> {
+        match self {
+            Gate::Other(x) | Gate::NOT(x) => vec![x],
+            Gate::AND(x, y) | Gate::OR(x, y) | Gate::XOR(x, y) | Gate::NAND(x, y) | Gate::NOR(x, y)
+                | Gate::ADD(x, y) | Gate::LSHIFT(x, y) | Gate::RSHIFT(x, y) => vec![x, y],
+            Gate::MUX(s, x, y) => vec![s, x, y],
+        }
+    }
+
+    /// Mnemonic of the gate (e.g. `AND`), empty for `Other`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gate::Other(_) => "",
+            Gate::NOT(_) => "NOT",
+            Gate::AND(_, _) => "AND",
+            Gate::OR(_, _) => "OR",
+            Gate::XOR(_, _) => "XOR",
+            Gate::NAND(_, _) => "NAND",
+            Gate::NOR(_, _) => "NOR",
+            Gate::ADD(_, _) => "ADD",
+            Gate::LSHIFT(_, _) => "LSHIFT",
+            Gate::RSHIFT(_, _) => "RSHIFT",
+            Gate::MUX(_, _, _) => "MUX",
+        }
+    }
+
+    /// Wires read by the gate.
+    pub fn wires(&self) -> Vec<&str> {
+        self.inputs().into_iter().filter_map(Input::wire).collect()
+    }
+
+    /// Copy of the gate, where every input is replaced.
+    pub fn map_inputs<F>(&self, mut f: F) -> Gate<T>
+        where F: FnMut(&Input<T>) -> Input<T> {
+
+        match self {
+            Gate::Other(x) => Gate::Other(f(x)),
+            Gate::NOT(x) => Gate::NOT(f(x)),
+            Gate::AND(x, y) => Gate::AND(f(x), f(y)),
+            Gate::OR(x, y) => Gate::OR(f(x), f(y)),
+            Gate::XOR(x, y) => Gate::XOR(f(x), f(y)),
+            Gate::NAND(x, y) => Gate::NAND(f(x), f(y)),
+            Gate::NOR(x, y) => Gate::NOR(f(x), f(y)),
+            Gate::ADD(x, y) => Gate::ADD(f(x), f(y)),
+            Gate::LSHIFT(x, y) => Gate::LSHIFT(f(x), f(y)),
+            Gate::RSHIFT(x, y) => Gate::RSHIFT(f(x), f(y)),
+            Gate::MUX(s, x, y) => Gate::MUX(f(s), f(x), f(y)),
+        }
+    }
+
+    /// Compute the output of the gate, given the signal on each of its inputs.
+    pub fn apply<F>(&self, mut signal: F) -> T
+        where F: FnMut(&Input<T>) -> T {
+
+        match self {
+            Gate::Other(x) => signal(x),
+            Gate::NOT(x) => !signal(x),
+            Gate::AND(x, y) => signal(x) & signal(y),
+            Gate::OR(x, y) => signal(x) | signal(y),
+            Gate::XOR(x, y) => signal(x) ^ signal(y),
+            Gate::NAND(x, y) => !(signal(x) & signal(y)),
+            Gate::NOR(x, y) => !(signal(x) | signal(y)),
+            Gate::ADD(x, y) => signal(x).add(signal(y)),
+            Gate::LSHIFT(x, y) => signal(x).shift_left(signal(y)),
+            Gate::RSHIFT(x, y) => signal(x).shift_right(signal(y)),
+            Gate::MUX(s, x, y) => if signal(s) == T::ZERO { signal(x) } else { signal(y) },
+        }
+    }
+}
+
+/// Build the circuit from a list of connections (e.g. `x AND y -> d`), indexed by wire.
+pub fn build_circuit<T: Word>(contents: &str) -> std::result::Result<HashMap<String, Gate<T>>, ParseError> {
+    let mut circuit = HashMap::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let (name, gate) = read_connection(line).map_err(|e| e.at_line(idx + 1))?;
+        circuit.insert(name.to_string(), gate);
+    }
+
+    Ok(circuit)
+}
+
+/* Read a connection (e.g. `x AND y -> d`), returning the wire and its gate. */
+fn read_connection<T: Word>(line: &str) -> std::result::Result<(&str, Gate<T>), ParseError> {
+    let mut iter = line.split(" -> ");
+    let gate = iter.next().unwrap_or("");
+    let gate = Gate::new(gate).map_err(|e| e.within(line, gate))?;
+    let name = next_token(&mut iter, line, "`->`")?.trim();
+
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(ParseError::new(line, name, "Invalid wire identifier."));
+    }
+
+    Ok((name, gate))
+}
+
+/// Render a netlist as a Graphviz DOT graph, optionally annotated with the signal on each wire.
+///
+/// Wires are ellipses (dashed if no gate is connected to them), gates are boxes labelled with
+/// their mnemonic and constants are plain text. A wire connected to `Other` is linked directly to
+/// its input. The netlist may be invalid, so that cycles can be spotted.
+pub fn to_dot<T: Word>(netlist: &HashMap<String, Gate<T>>, signals: Option<&HashMap<String, T>>)
+        -> String {
+    let mut output = String::from("digraph circuit {\n");
+
+    let mut wires: Vec<&String> = netlist.keys().collect();
+    wires.sort_unstable();
+
+    for wire in wires {
+        let gate = &netlist[wire];
+
+        match signals.and_then(|signals| signals.get(wire)) {
+            Some(signal) => writeln!(output, "    {} [label={}];", quote(wire), quote(&format!("{}\n{}", wire, signal))),
+            None => writeln!(output, "    {};", quote(wire)),
+        }.unwrap();
+
+        /* Gates are named after their output wire. */
+        let target = match gate {
+            Gate::Other(_) => quote(wire),
+            _ => {
+                let node = quote(&format!("{} {}", gate.name(), wire));
+                writeln!(output, "    {} [shape=box, label={}];", node, quote(gate.name())).unwrap();
+                writeln!(output, "    {} -> {};", node, quote(wire)).unwrap();
+                node
+            },
+        };
+
+        /* Tell the inputs apart when the order matters. */
+        let labels: &[&str] = match gate {
+            Gate::LSHIFT(_, _) | Gate::RSHIFT(_, _) => &["", "amount"],
+            Gate::MUX(_, _, _) => &["select", "0", "1"],
+            _ => &[],
+        };
+
+        for (idx, input) in gate.inputs().into_iter().enumerate() {
+            let source = match input {
+                Input::Wire(w) => {
+                    if !netlist.contains_key(w) {
+                        writeln!(output, "    {} [style=dashed];", quote(w)).unwrap();
+                    }
+                    quote(w)
+                },
+                Input::Signal(x) => {
+                    let node = quote(&format!("{} {} {}", x, wire, idx));
+                    writeln!(output, "    {} [shape=plaintext, label={}];", node, quote(&x.to_string())).unwrap();
+                    node
+                },
+            };
+
+            match labels.get(idx) {
+                Some(label) if !label.is_empty() =>
+                    writeln!(output, "    {} -> {} [label={}];", source, target, quote(label)),
+                _ => writeln!(output, "    {} -> {};", source, target),
+            }.unwrap();
+        }
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/* Identifier or label in the DOT language. */
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+/// Error found when sorting the wires of a circuit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CircuitError {
+    /// A gate (connected to `used_by`) reads a wire which isn't connected to any gate.
+    UndefinedWire {
+        /// Wire which isn't connected.
+        wire: String,
+        /// Wire of the gate reading it.
+        used_by: String,
+    },
+    /// Every wire depends on the next one, and the last one on the first one.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CircuitError::UndefinedWire { wire, used_by } =>
+                write!(f, "Undefined wire `{}`, used by wire `{}`.", wire, used_by),
+            CircuitError::Cycle(wires) => {
+                write!(f, "Cycle between wires: ")?;
+                for wire in wires {
+                    write!(f, "`{}` <- ", wire)?;
+                }
+                write!(f, "`{}`.", wires[0])
+            },
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+/// Number of gates before and after simplifying a circuit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Simplification {
+    /// Number of gates of the original circuit.
+    pub before: usize,
+    /// Number of gates of the simplified circuit.
+    pub after: usize,
+}
+
+impl fmt::Display for Simplification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} gates before simplification, {} after.", self.before, self.after)
+    }
+}
+
+/// Circuit whose wires are sorted, so that every wire comes after the wires its gate reads.
+///
+/// Signals are computed lazily and cached. Wires can be overridden with a constant signal, which
+/// only invalidates the cached signals downstream of the wire.
+pub struct Circuit<T = u16> {
+    gates: HashMap<String, Gate<T>>,
+    order: Vec<String>,
+    /// Wires reading each wire.
+    dependents: HashMap<String, Vec<String>>,
+    overrides: HashMap<String, T>,
+    cache: HashMap<String, T>,
+}
+
+/* State of a wire while sorting the circuit. */
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+impl<T: Word> Circuit<T> {
+    /// Sort the wires of a circuit, failing if a gate reads an undefined wire or if wires
+    /// depend on each other.
+    pub fn new(gates: HashMap<String, Gate<T>>) -> std::result::Result<Circuit<T>, CircuitError> {
+        let mut visits: HashMap<&str, Visit> = HashMap::new();
+        let mut order = Vec::with_capacity(gates.len());
+
+        /* Sort the wires, so that errors don't depend on the hash map order. */
+        let mut wires: Vec<&str> = gates.keys().map(String::as_str).collect();
+        wires.sort_unstable();
+
+        /* Depth-first search, where the stack holds the wires being visited,
+         * with the index of the next input to visit. */
+        for root in wires {
+            if visits.contains_key(root) {
+                continue;
+            }
+
+            let mut stack = vec![(root, 0)];
+            visits.insert(root, Visit::InProgress);
+
+            while let Some((wire, idx)) = stack.last_mut() {
+                let wire: &str = wire;
+
+                match gates[wire].wires().get(*idx) {
+                    Some(&input) => {
+                        *idx += 1;
+
+                        match visits.get(input) {
+                            Some(Visit::Done) => (),
+                            Some(Visit::InProgress) => {
+                                let start = stack.iter().position(|&(w, _)| w == input).unwrap();
+                                return Err(CircuitError::Cycle(
+                                    stack[start..].iter().map(|(w, _)| w.to_string()).collect()));
+                            },
+                            None if gates.contains_key(input) => {
+                                visits.insert(input, Visit::InProgress);
+                                stack.push((input, 0));
+                            },
+                            None => return Err(CircuitError::UndefinedWire {
+                                wire: input.to_string(),
+                                used_by: wire.to_string(),
+                            }),
+                        }
+                    },
+                    None => {
+                        visits.insert(wire, Visit::Done);
+                        order.push(wire.to_string());
+                        stack.pop();
+                    },
+                }
+            }
+        }
+
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+        for (wire, gate) in &gates {
+            for input in gate.wires() {
+                dependents.entry(input.to_string()).or_default().push(wire.clone());
+            }
+        }
+
+        Ok(Circuit { gates, order, dependents, overrides: HashMap::new(), cache: HashMap::new() })
+    }
+
+    /// Gate connected to every wire.
+    pub fn gates(&self) -> &HashMap<String, Gate<T>> {
+        &self.gates
+    }
+
+    /// Wires, sorted so that every wire comes after the wires its gate reads.
+    pub fn order(&self) -> &[String] {
+        &self.order
+    }
+
+    /// Render the circuit as a Graphviz DOT graph (see `to_dot`), annotated with the signal on
+    /// every wire.
+    pub fn to_dot(&mut self) -> String {
+        self.evaluate();
+        to_dot(&self.gates, Some(&self.cache))
+    }
+
+    /// Compute the signal on every wire.
+    pub fn evaluate(&mut self) -> &HashMap<String, T> {
+        for idx in 0..self.order.len() {
+            if !self.cache.contains_key(&self.order[idx]) {
+                let wire = self.order[idx].clone();
+                let signal = self.compute(&wire);
+                self.cache.insert(wire, signal);
+            }
+        }
+
+        &self.cache
+    }
+
+    /// Compute the signal on a wire (and on the wires it depends on), returning `None` if the
+    /// wire doesn't exist.
+    pub fn signal(&mut self, wire: &str) -> Option<T> {
+        if !self.gates.contains_key(wire) {
+            return None;
+        }
+
+        /* The stack holds the wires to compute, after the wires they read. */
+        let mut stack = vec![wire.to_string()];
+
+        while let Some(current) = stack.last() {
+            if self.cache.contains_key(current) {
+                stack.pop();
+                continue;
+            }
+
+            let missing: Vec<String> = if self.overrides.contains_key(current) {
+                vec![]
+            } else {
+                self.gates[current].wires().into_iter()
+                    .filter(|input| !self.cache.contains_key(*input))
+                    .map(str::to_string)
+                    .collect()
+            };
+
+            if missing.is_empty() {
+                let current = stack.pop().unwrap();
+                let signal = self.compute(&current);
+                self.cache.insert(current, signal);
+            } else {
+                stack.extend(missing);
+            }
+        }
+
+        self.cache.get(wire).cloned()
+    }
+
+    /// Signal on a wire if it has already been computed.
+    pub fn cached_signal(&self, wire: &str) -> Option<T> {
+        self.cache.get(wire).cloned()
+    }
+
+    /// Replace the gate connected to a wire by a constant signal, returning `None` if the wire
+    /// doesn't exist.
+    pub fn override_wire(&mut self, wire: &str, signal: T) -> Option<()> {
+        if !self.gates.contains_key(wire) {
+            return None;
+        }
+
+        self.overrides.insert(wire.to_string(), signal);
+        self.invalidate(wire);
+        Some(())
+    }
+
+    /// Reconnect a wire to its gate, if it was overridden.
+    pub fn clear_override(&mut self, wire: &str) {
+        if self.overrides.remove(wire).is_some() {
+            self.invalidate(wire);
+        }
+    }
+
+    /// Simplify the circuit, keeping the `outputs` wires (and what they depend on). Gates whose
+    /// inputs are constant are folded, chains of wires forwarding another wire are collapsed, and
+    /// wires which the outputs don't depend on are removed. The `inputs` wires are kept as they
+    /// are, to be overridden later. Returns `None` if one of the wires doesn't exist.
+    pub fn simplify(&self, inputs: &[&str], outputs: &[&str]) -> Option<(Circuit<T>, Simplification)> {
+        if inputs.iter().chain(outputs).any(|wire| !self.gates.contains_key(*wire)) {
+            return None;
+        }
+
+        /* What every wire comes down to: a constant, or a wire which is kept. */
+        let mut resolved: HashMap<&str, Input<T>> = HashMap::new();
+        let mut gates: HashMap<String, Gate<T>> = HashMap::new();
+
+        for wire in &self.order {
+            let gate = match self.overrides.get(wire) {
+                Some(&signal) => Gate::Other(Input::Signal(signal)),
+                None => self.gates[wire].map_inputs(|input| match input {
+                    Input::Wire(w) => resolved[w.as_str()].clone(),
+                    signal => signal.clone(),
+                }),
+            };
+
+            /* A multiplexer with a constant selection forwards one of its inputs. */
+            let gate = match gate {
+                Gate::MUX(Input::Signal(s), x, _) if s == T::ZERO => Gate::Other(x),
+                Gate::MUX(Input::Signal(_), _, y) => Gate::Other(y),
+                gate => gate,
+            };
+
+            let constant = gate.inputs().iter().all(|input| input.wire().is_none());
+
+            let value = match &gate {
+                _ if inputs.contains(&wire.as_str()) => None,
+                _ if constant => Some(Input::Signal(gate.apply(|input| match input {
+                    Input::Signal(x) => *x,
+                    Input::Wire(_) => unreachable!(),
+                }))),
+                Gate::Other(Input::Wire(w)) => Some(Input::Wire(w.clone())),
+                _ => None,
+            };
+
+            match value {
+                Some(value) => { resolved.insert(wire, value); },
+                None => {
+                    resolved.insert(wire, Input::Wire(wire.clone()));
+                    gates.insert(wire.clone(), gate);
+                },
+            }
+        }
+
+        /* The outputs must stay, even if they are folded or forward another wire. */
+        for &wire in outputs {
+            if !gates.contains_key(wire) {
+                gates.insert(wire.to_string(), Gate::Other(resolved[wire].clone()));
+            }
+        }
+
+        /* Remove the wires which the outputs don't depend on. */
+        let mut alive: Vec<&str> = outputs.to_vec();
+        let mut to_do: Vec<&str> = outputs.to_vec();
+        while let Some(wire) = to_do.pop() {
+            for input in gates[wire].wires() {
+                if !alive.contains(&input) {
+                    alive.push(input);
+                    to_do.push(input);
+                }
+            }
+        }
+        let gates: HashMap<String, Gate<T>> = alive.into_iter()
+            .map(|wire| (wire.to_string(), gates[wire].clone()))
+            .collect();
+
+        let simplification = Simplification { before: self.gates.len(), after: gates.len() };
+
+        /* Simplifying can't create cycles or undefined wires. */
+        Some((Circuit::new(gates).unwrap(), simplification))
+    }
+
+    /// Find the signals on the `input` wire, among the `candidates` (e.g. `0..=u16::MAX`), which
+    /// produce the `target` signal on the `output` wire. Returns `None` if one of the wires
+    /// doesn't exist.
+    pub fn solve<I>(&self, input: &str, output: &str, target: T, candidates: I) -> Option<Vec<T>>
+        where I: IntoIterator<Item = T> {
+
+        let (circuit, _) = self.simplify(&[input], &[output])?;
+
+        /* Each candidate reevaluates the whole simplified circuit, so its wires are numbered in
+         * topological order, and each gate refers to the numbers of its input wires. */
+        let index: HashMap<&str, usize> = circuit.order.iter().enumerate()
+            .map(|(idx, wire)| (wire.as_str(), idx))
+            .collect();
+        let program: Vec<(&Gate<T>, Vec<usize>)> = circuit.order.iter()
+            .map(|wire| {
+                let gate = &circuit.gates[wire];
+                (gate, gate.wires().iter().map(|w| index[w]).collect())
+            })
+            .collect();
+        let input = index.get(input).cloned();
+        let output = index[output];
+
+        let mut signals = vec![T::ZERO; program.len()];
+        let mut solutions = Vec::new();
+
+        for candidate in candidates {
+            for (idx, (gate, wires)) in program.iter().enumerate() {
+                signals[idx] = if Some(idx) == input {
+                    candidate
+                } else {
+                    let mut wires = wires.iter();
+                    gate.map_inputs(|input| match input {
+                        Input::Signal(x) => Input::Signal(*x),
+                        Input::Wire(_) => Input::Signal(signals[*wires.next().unwrap()]),
+                    }).apply(|input| match input {
+                        Input::Signal(x) => *x,
+                        Input::Wire(_) => unreachable!(),
+                    })
+                };
+            }
+
+            if signals[output] == target {
+                solutions.push(candidate);
+            }
+        }
+
+        Some(solutions)
+    }
+
+    /* Forget the signals on a wire and on the wires downstream. A wire is only
+     * computed after its inputs, so the wires downstream of a wire which isn't
+     * cached can't be cached either. */
+    fn invalidate(&mut self, wire: &str) {
+        let mut to_do = vec![wire.to_string()];
+
+        while let Some(current) = to_do.pop() {
+            if self.cache.remove(&current).is_some() {
+                if let Some(dependents) = self.dependents.get(&current) {
+                    to_do.extend(dependents.iter().cloned());
+                }
+            }
+        }
+    }
+
+    /* Compute the signal on a wire, whose inputs must already be cached. */
+    fn compute(&self, wire: &str) -> T {
+        if let Some(&signal) = self.overrides.get(wire) {
+            return signal;
+        }
+
+        self.gates[wire].apply(|input| match input {
+            Input::Signal(x) => *x,
+            Input::Wire(w) => self.cache[w],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\n\
+        y RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i\n";
+
+    #[test]
+    fn example_circuit() {
+        let mut circuit: Circuit = Circuit::new(build_circuit(EXAMPLE).unwrap()).unwrap();
+        let signals = circuit.evaluate();
+        let expected = [("d", 72), ("e", 507), ("f", 492), ("g", 114), ("h", 65412), ("i", 65079),
+            ("x", 123), ("y", 456)];
+
+        assert_eq!(signals.len(), expected.len());
+        for &(wire, signal) in &expected {
+            assert_eq!(signals[wire], signal);
+        }
+    }
+
+    #[test]
+    fn override_wire() {
+        let mut circuit: Circuit = Circuit::new(build_circuit(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(circuit.signal("d"), Some(72));
+        assert_eq!(circuit.cached_signal("e"), None);
+        circuit.evaluate();
+
+        /* Only the wires downstream of x are invalidated. */
+        circuit.override_wire("x", 1).unwrap();
+        let cached: Vec<_> = ["x", "d", "e", "f", "h", "y", "g", "i"].iter()
+            .map(|wire| circuit.cached_signal(wire).is_some())
+            .collect();
+        assert_eq!(cached, [false, false, false, false, false, true, true, true]);
+
+        assert_eq!(circuit.signal("e"), Some(457));
+        assert_eq!(circuit.signal("h"), Some(65534));
+        assert_eq!(circuit.override_wire("z", 1), None);
+
+        circuit.clear_override("x");
+        assert_eq!(circuit.signal("h"), Some(65412));
+    }
+
+    #[test]
+    fn word_width() {
+        let netlist = "200 -> x\n100 -> y\nx ADD y -> s\nx XOR y -> a\nx NAND y -> b\n\
+            x NOR y -> c\nMUX a x y -> m\nMUX 0 x y -> n\nx LSHIFT 9 -> l\n";
+
+        let mut circuit: Circuit<u8> = Circuit::new(build_circuit(netlist).unwrap()).unwrap();
+        let expected = [("s", 44), ("a", 172), ("b", 191), ("c", 19), ("m", 100), ("n", 200), ("l", 0)];
+        for &(wire, signal) in &expected {
+            assert_eq!(circuit.signal(wire), Some(signal));
+        }
+
+        let mut circuit: Circuit<u64> = Circuit::new(build_circuit(netlist).unwrap()).unwrap();
+        assert_eq!(circuit.signal("s"), Some(300));
+        assert_eq!(circuit.signal("b"), Some(!64));
+        assert_eq!(circuit.signal("l"), Some(200 << 9));
+
+        assert!(build_circuit::<u8>("256 -> x").is_err());
+        assert_eq!(Gate::<u8>::new("MUX s x y z").unwrap_err().token, "z");
+    }
+
+    #[test]
+    fn dot_export() {
+        let netlist = build_circuit::<u16>("123 -> x\nx RSHIFT 2 -> y\ny -> a\nNOT z -> b\n").unwrap();
+        let expected = r#"digraph circuit {
+    "a";
+    "y" -> "a";
+    "b";
+    "NOT b" [shape=box, label="NOT"];
+    "NOT b" -> "b";
+    "z" [style=dashed];
+    "z" -> "NOT b";
+    "x";
+    "123 x 0" [shape=plaintext, label="123"];
+    "123 x 0" -> "x";
+    "y";
+    "RSHIFT y" [shape=box, label="RSHIFT"];
+    "RSHIFT y" -> "y";
+    "x" -> "RSHIFT y";
+    "2 y 1" [shape=plaintext, label="2"];
+    "2 y 1" -> "RSHIFT y" [label="amount"];
+}
+"#;
+        assert_eq!(to_dot(&netlist, None), expected);
+
+        let mut circuit: Circuit = Circuit::new(build_circuit("123 -> x\nx -> a\n").unwrap()).unwrap();
+        assert!(circuit.to_dot().contains("\"a\" [label=\"a\\n123\"];"));
+    }
+
+    #[test]
+    fn simplify() {
+        let netlist = "123 -> x\nb -> c\nc -> d\nx AND d -> e\nMUX 1 y e -> f\nf -> a\n\
+            NOT x -> h\n456 -> y\n3 -> b\n";
+        let mut circuit: Circuit = Circuit::new(build_circuit(netlist).unwrap()).unwrap();
+
+        let (mut simplified, simplification) = circuit.simplify(&["b"], &["a"]).unwrap();
+        assert_eq!(simplification, Simplification { before: 9, after: 3 });
+        assert_eq!(simplified.gates()["a"], Gate::Other(Input::Wire("e".to_string())));
+        assert_eq!(simplified.gates()["e"], Gate::AND(Input::Signal(123), Input::Wire("b".to_string())));
+        assert_eq!(simplified.gates()["b"], Gate::Other(Input::Signal(3)));
+
+        for &b in &[3, 1000] {
+            circuit.override_wire("b", b);
+            simplified.override_wire("b", b);
+            assert_eq!(simplified.signal("a"), circuit.signal("a"));
+        }
+
+        let (simplified, simplification) = circuit.simplify(&[], &["a", "h"]).unwrap();
+        assert_eq!(simplification.after, 2);
+        assert_eq!(simplified.gates()["a"], Gate::Other(Input::Signal(123 & 1000)));
+        assert!(circuit.simplify(&[], &["z"]).is_none());
+    }
+
+    #[test]
+    fn solve() {
+        let netlist = "b RSHIFT 8 -> c\nc AND 3 -> a\n5 -> b\n7 -> d\n";
+        let circuit: Circuit = Circuit::new(build_circuit(netlist).unwrap()).unwrap();
+
+        let solutions = circuit.solve("b", "a", 2, 0..=u16::MAX).unwrap();
+        assert_eq!(solutions.len(), 16384);
+        assert!(solutions.iter().all(|&b| (b >> 8) & 3 == 2));
+        assert_eq!(circuit.solve("b", "a", 4, 0..=u16::MAX), Some(Vec::new()));
+
+        /* `a` doesn't depend on `d`: any value works if `a` already has the target. */
+        assert_eq!(circuit.solve("d", "a", 0, 0..4), Some(vec![0, 1, 2, 3]));
+        assert_eq!(circuit.solve("d", "a", 1, 0..4), Some(Vec::new()));
+        assert!(circuit.solve("z", "a", 0, 0..4).is_none());
+    }
+
+    #[test]
+    fn invalid_circuits() {
+        let error = Circuit::<u16>::new(build_circuit("x AND y -> d\n1 -> x\n").unwrap()).err();
+        assert_eq!(error, Some(CircuitError::UndefinedWire { wire: "y".to_string(), used_by: "d".to_string() }));
+
+        let error = Circuit::<u16>::new(build_circuit("b -> a\nNOT c -> b\na OR 1 -> c\n1 -> d\n").unwrap()).err();
+        assert_eq!(error, Some(CircuitError::Cycle(vec!["a".to_string(), "b".to_string(), "c".to_string()])));
+        assert_eq!(error.unwrap().to_string(), "Cycle between wires: `a` <- `b` <- `c` <- `a`.");
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 8: Matchsticks (<https://adventofcode.com/2015/day/8>).
+
+use crate::error::ParseError;
+use crate::solver::{Result, Solver};
+
+/// Santa's list is a file of string literals, which must be decoded (and encoded) to compare
+/// the number of characters of code with the number of characters in memory.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(input).to_string())
+    }
+}
+
+/// Number of characters of code minus the number of characters in memory.
+pub fn part1(contents: &str) -> std::result::Result<usize, ParseError> {
+    /* The decoded string has already lost its double quotes. */
+    contents.lines()
+        .enumerate()
+        .try_fold(0, |acc, (idx, line)| {
+            let decoded = decode(line).map_err(|e| e.at_line(idx + 1))?;
+            Ok(acc + line.len() - decoded.len())
+        })
+}
+
+/// Number of characters of the newly encoded strings minus the number of characters of code.
+pub fn part2(contents: &str) -> usize {
+    /* Add 1 for each special character (\, "), plus 2 for the extra quotes.
+     * Unlike `encode`, the puzzle's encoding escapes nothing else. */
+    contents.lines()
+        .fold(0, |acc, line| acc + 2 + line.matches('\\').count()
+                                 + line.matches('"').count())
+}
+
+/// Encode bytes as a double-quoted string literal, the reverse of `decode`. Only `\` and `"` are
+/// escaped, except for bytes which aren't printable ASCII characters (`\x` escape sequences).
+pub fn encode(s: &[u8]) -> String {
+    let mut result = String::from("\"");
+
+    for &c in s {
+        match c {
+            b'\\' | b'"' => {
+                result.push('\\');
+                result.push(c as char);
+            },
+            b' '..=b'~' => result.push(c as char),
+            _ => result.push_str(&format!("\\x{:02x}", c)),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/// Decode a double-quoted string literal, handling the `\\`, `\"` and `\x` escape sequences.
+pub fn decode(line: &str) -> std::result::Result<Vec<u8>, ParseError> {
+    if line.len() < 2 || !line.starts_with('"') || !line.ends_with('"') {
+        return Err(ParseError::new(line, line, "Not a double-quoted string."));
+    }
+
+    /* Ignore the double quotes. */
+    let s = &line.as_bytes()[..line.len() - 1];
+
+    let mut result = Vec::new();
+    let mut idx = 1;
+
+    while idx < s.len() {
+        match s[idx] {
+            b'\\' => {
+                idx += 1;
+                match s.get(idx) {
+                    Some(b'x') => {
+                        let token = line.get(idx-1..idx+3).unwrap_or(&line[idx-1..]);
+                        let code = line.get(idx+1..idx+3)
+                            .and_then(|code| u8::from_str_radix(code, 16).ok())
+                            .ok_or_else(|| ParseError::new(line, token, "Invalid hexadecimal escape."))?;
+                        result.push(code);
+                        idx += 3;
+                    }
+                    Some(&c) => {
+                        result.push(c);
+                        idx += 1;
+                    }
+                    None => return Err(ParseError::new(line, &line[idx-1..], "Unterminated escape.")),
+                }
+            },
+            _ => {
+                result.push(s[idx]);
+                idx += 1;
+            },
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27"
+"#;
+
+    #[test]
+    fn decoded_strings() {
+        assert_eq!(decode(r#""""#).unwrap(), b"");
+        assert_eq!(decode(r#""abc""#).unwrap(), b"abc");
+        assert_eq!(decode(r#""aaa\"aaa""#).unwrap(), b"aaa\"aaa");
+        assert_eq!(decode(r#""\x27""#).unwrap(), b"'");
+        assert_eq!(part1(EXAMPLE).unwrap(), 23 - 11);
+    }
+
+    #[test]
+    fn encoded_strings() {
+        assert_eq!(part2(EXAMPLE), 42 - 23);
+        /* Only `\` and `"` are escaped, even for tabs and non-ASCII characters. */
+        assert_eq!(part2("\"a\tb\u{e9}\""), 4);
+    }
+
+    proptest! {
+        #[test]
+        fn encode_then_decode(s in prop::collection::vec(any::<u8>(), 0..50)) {
+            prop_assert_eq!(decode(&encode(&s)).unwrap(), s);
+        }
+
+        #[test]
+        fn decoding_shrinks(s in "[ -~]*") {
+            let line = encode(s.as_bytes());
+            prop_assert!(line.len() >= s.len() + 2);
+
+            /* Not every string is a valid literal, but none should panic. */
+            if let Ok(decoded) = decode(&format!("\"{}\"", s)) {
+                prop_assert!(decoded.len() <= s.len());
+            }
+            let _ = decode(&s);
+        }
+    }
+}
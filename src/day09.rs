@@ -0,0 +1,124 @@
+//! Day 9: All in a Single Night (<https://adventofcode.com/2015/day/9>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use permutohedron::Heap;
+use std::collections::{HashSet, HashMap};
+
+/// Santa has to visit every location exactly once, given the distances between every pair of
+/// locations.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let distances = read_input(input)?;
+        Ok(part1(&cities(&distances), &distances).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let distances = read_input(input)?;
+        Ok(part2(&cities(&distances), &distances).to_string())
+    }
+}
+
+/// Every city appearing in the distance table.
+pub fn cities(distances: &HashMap<[String; 2], u32>) -> HashSet<String> {
+    distances.keys()
+        .map(|k| k[0].to_string())
+        .collect()
+}
+
+/// Length of the shortest route visiting every city.
+pub fn part1(cities: &HashSet<String>, distances: &HashMap<[String; 2], u32>)
+        -> u32 {
+    let mut v: Vec<String> = cities.iter().map(|x| x.to_string()).collect();
+    let heap = Heap::new(&mut v);
+
+    heap.map(|path| {
+            path.as_slice()
+                .windows(2)
+                .fold(0, |acc, p| acc + distances[p])
+            })
+        .min()
+        .unwrap()
+}
+
+/// Length of the longest route visiting every city.
+pub fn part2(cities: &HashSet<String>, distances: &HashMap<[String; 2], u32>)
+        -> u32 {
+    let mut v: Vec<String> = cities.iter().map(|x| x.to_string()).collect();
+    let heap = Heap::new(&mut v);
+
+    heap.map(|path| {
+            path.as_slice()
+                .windows(2)
+                .fold(0, |acc, p| acc + distances[p])
+            })
+        .max()
+        .unwrap()
+}
+
+/// Read the distances (e.g. `London to Dublin = 464`), in both directions.
+pub fn read_input(contents: &str) -> std::result::Result<HashMap<[String; 2], u32>, ParseError> {
+    let mut distances = HashMap::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let (city_a, city_b, distance) = read_line(line).map_err(|e| e.at_line(idx + 1))?;
+        distances.insert([city_a.to_string(), city_b.to_string()], distance);
+        distances.insert([city_b.to_string(), city_a.to_string()], distance);
+    }
+
+    /* Every route must be possible, so every pair of cities needs a distance. */
+    let end = contents.lines().count() + 1;
+    let cities = cities(&distances);
+    if cities.is_empty() {
+        return Err(ParseError::missing("", "Missing distances.").at_line(end));
+    }
+    for city_a in &cities {
+        for city_b in cities.iter().filter(|&city_b| city_b != city_a) {
+            if !distances.contains_key(&[city_a.clone(), city_b.clone()]) {
+                let message = format!("Missing the distance from {} to {}.", city_a, city_b);
+                return Err(ParseError::missing("", &message).at_line(end));
+            }
+        }
+    }
+
+    Ok(distances)
+}
+
+fn read_line(line: &str) -> std::result::Result<(&str, &str, u32), ParseError> {
+    let mut cities_distance = line.split(" = ");
+    let mut cities = next_token(&mut cities_distance, line, "cities")?.split(" to ");
+    let city_a = next_token(&mut cities, line, "city")?;
+    let city_b = next_token(&mut cities, line, "`to`")?;
+    let distance = parse_token(line, next_token(&mut cities_distance, line, "`=`")?)?;
+
+    Ok((city_a, city_b, distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141\n";
+
+    #[test]
+    fn shortest_route() {
+        let distances = read_input(EXAMPLE).unwrap();
+        assert_eq!(part1(&cities(&distances), &distances), 605);
+    }
+
+    #[test]
+    fn longest_route() {
+        let distances = read_input(EXAMPLE).unwrap();
+        assert_eq!(part2(&cities(&distances), &distances), 982);
+    }
+
+    #[test]
+    fn missing_distance() {
+        assert_eq!(read_input("").unwrap_err().message, "Missing distances.");
+        let error = read_input("London to Dublin = 464\nLondon to Belfast = 518\n").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert!(error.message.contains("Belfast") && error.message.contains("Dublin"));
+    }
+}
@@ -0,0 +1,108 @@
+//! Day 10: Elves Look, Elves Say (<https://adventofcode.com/2015/day/10>).
+
+use crate::solver::{Result, Solver};
+
+/// The elves are playing look-and-say: each round, runs of digits are replaced by the number of
+/// digits followed by the digit itself.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(play(input, 40).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(play(input, 50).to_string())
+    }
+}
+
+/// Length of the result after applying look-and-say `rounds` times to the puzzle input.
+pub fn play(puzzle_input: &str, rounds: usize) -> usize {
+    let puzzle_input = format!("{}\n", puzzle_input.trim());
+
+    /* Remember to remove the line feed when counting length. */
+    (0..rounds)
+        .fold(puzzle_input, |outcome, _| look_and_say(&outcome))
+        .len() - 1
+}
+
+/// Apply one round of look-and-say. The sequence must be terminated by a line feed, which is
+/// also appended to the result (an empty sequence gives a lone line feed).
+pub fn look_and_say(s: &str) -> String {
+    let mut result = String::new();
+    let mut capture = match s.chars().next() {
+        Some(c) => (c, 0),
+        None => return String::from("\n"),
+    };
+
+    s.chars()
+        .for_each(|c|
+            if capture.0 == c {
+                capture.1 += 1;
+            }
+            else {
+                result.push_str(&capture.1.to_string());
+                result.push(capture.0);
+                capture = (c, 1);
+            }
+        );
+
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn look_and_say_1() {
+        assert_eq!(look_and_say("1\n"), String::from("11\n"));
+    }
+
+    #[test]
+    fn look_and_say_11() {
+        assert_eq!(look_and_say("11\n"), String::from("21\n"));
+    }
+
+    #[test]
+    fn look_and_say_21() {
+        assert_eq!(look_and_say("21\n"), String::from("1211\n"));
+    }
+
+    #[test]
+    fn five_rounds() {
+        /* 1, 11, 21, 1211, 111221, 312211 */
+        assert_eq!(play("1", 5), 6);
+    }
+
+    /* Runs of 1 to 9 identical digits, each next to runs of other digits. */
+    fn runs() -> impl Strategy<Value = Vec<(char, usize)>> {
+        prop::collection::vec((1..=9u8, 1..=9usize), 0..20).prop_map(|runs| {
+            let mut result: Vec<(char, usize)> = Vec::new();
+            for (digit, length) in runs {
+                let c = (b'0' + digit) as char;
+                if result.last().map(|run| run.0) != Some(c) {
+                    result.push((c, length));
+                }
+            }
+            result
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn describes_every_run(runs in runs()) {
+            let sequence: String = runs.iter().map(|&(c, length)| c.to_string().repeat(length)).collect();
+            let description: String = runs.iter().map(|&(c, length)| format!("{}{}", length, c)).collect();
+
+            prop_assert_eq!(look_and_say(&format!("{}\n", sequence)), format!("{}\n", description));
+        }
+
+        #[test]
+        fn any_sequence(s in "\\PC*") {
+            prop_assert!(look_and_say(&s).ends_with('\n'));
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Day 11: Corporate Policy (<https://adventofcode.com/2015/day/11>).
+
+use crate::error::ParseError;
+use crate::solver::{Result, Solver};
+use std::collections::HashSet;
+
+/// Santa's password expired, and the new one is found by incrementing the old one until it meets
+/// the security requirements.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let mut password = read_password(input)?;
+        Ok(password.new_password())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let mut password = read_password(input)?;
+        password.new_password();
+        Ok(password.new_password())
+    }
+}
+
+/// Password made of lowercase letters. Iterating increments the password, like a number in
+/// base 26 ("xx", "xy", "xz", "ya", ...). Once every letter is a `z`, the password grows by one
+/// letter ("zz", "aaa", ...).
+pub struct AocPassword(Vec<u8>);
+
+impl AocPassword {
+    /// Create a password from its letters, as bytes.
+    pub fn from_slice(v: &[u8]) -> AocPassword {
+        AocPassword(v.to_vec())
+    }
+
+    /// Contains a straight of at least three increasing letters (e.g. `abc`).
+    pub fn increasing_letters(&self) -> bool {
+        self.0.windows(3)
+            .any(|w| w[0] + 1 == w[1] && w[1] + 1 == w[2])
+    }
+
+    /// Doesn't contain the letters `i`, `o` or `l`.
+    pub fn no_confusing_letter(&self) -> bool {
+        !self.0.iter().any(|c| b"iol".contains(c))
+    }
+
+    /// Contains at least two different, non-overlapping pairs of letters (e.g. `aa` and `bb`).
+    pub fn two_different_pairs(&self) -> bool {
+        let mut h = HashSet::new();
+
+        self.0.windows(2)
+            .for_each(|w| if w[0] == w[1] {h.insert(w[0]);});
+
+        h.len() > 1
+    }
+
+    /// Increment the password until it meets every requirement.
+    pub fn new_password(&mut self) -> String {
+        self.next();
+        while !self.increasing_letters() || !self.no_confusing_letter() ||
+                !self.two_different_pairs() {
+            self.next();
+        }
+
+        String::from_utf8_lossy(&self.0).to_string()
+    }
+}
+
+/// Read the current password, which must only contain lowercase letters.
+pub fn read_password(input: &str) -> std::result::Result<AocPassword, ParseError> {
+    let password = input.trim();
+
+    match password.find(|c: char| !c.is_ascii_lowercase()) {
+        Some(idx) => {
+            let c = password[idx..].chars().next().unwrap();
+            Err(ParseError::new(password, &password[idx..idx + c.len_utf8()], "Not a lowercase letter."))
+        },
+        None if password.is_empty() => Err(ParseError::missing(password, "Missing password.")),
+        None => Ok(AocPassword::from_slice(password.as_bytes())),
+    }
+}
+
+impl Iterator for AocPassword {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut increment = true;
+        let mut iter = self.0.iter_mut().rev();
+
+        while increment {
+            match iter.next() {
+                Some(x) => *x = match x {
+                    b'z' => b'a',
+                    _ => {increment = false; *x + 1},
+                },
+                None => break,
+            }
+        }
+
+        if increment {
+            self.0.insert(0, b'a');
+        }
+
+        Some(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_aoc_password() {
+        let actual = AocPassword::from_slice(b"aa").next();
+        let expected = [b'a', b'b'];
+        assert_eq!(actual.unwrap(), expected)
+    }
+
+    #[test]
+    fn test_generator() {
+        let actual = AocPassword::from_slice(b"abcdefgh").new_password();
+        let expected = String::from("abcdffaa");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_generator_2() {
+        let actual = AocPassword::from_slice(b"ghijklmn").new_password();
+        let expected = String::from("ghjaabcc");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn requirements() {
+        let password = AocPassword::from_slice(b"hijklmmn");
+        assert!(password.increasing_letters());
+        assert!(!password.no_confusing_letter());
+
+        let password = AocPassword::from_slice(b"abbceffg");
+        assert!(password.two_different_pairs());
+        assert!(!password.increasing_letters());
+
+        assert!(!AocPassword::from_slice(b"abbcegjk").two_different_pairs());
+    }
+
+    /* Passwords are numbers in bijective base 26, where `a` is 1 and `z` is
+     * 26, so that incrementing `zz` gives `aaa`. */
+    fn value(password: &[u8]) -> u64 {
+        password.iter().fold(0, |acc, &c| acc * 26 + u64::from(c - b'a' + 1))
+    }
+
+    proptest! {
+        #[test]
+        fn increment_by_one(password in "[a-z]{0,10}") {
+            let next = AocPassword::from_slice(password.as_bytes()).next().unwrap();
+            prop_assert_eq!(value(&next), value(password.as_bytes()) + 1);
+        }
+
+        #[test]
+        fn wrap_around(length in 1..20usize) {
+            let next = AocPassword::from_slice(&vec![b'z'; length]).next().unwrap();
+            prop_assert_eq!(next, vec![b'a'; length + 1]);
+        }
+    }
+}
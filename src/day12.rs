@@ -0,0 +1,77 @@
+//! Day 12: JSAbacusFramework.io (<https://adventofcode.com/2015/day/12>).
+
+use crate::error::ParseError;
+use crate::solver::{Result, Solver};
+use serde_json::Value;
+
+/// Santa's accounting elves need help balancing the books, stored as a JSON document.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(sum(&read_input(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(filtered_sum(&read_input(input)?).to_string())
+    }
+}
+
+/// Read the JSON document.
+pub fn read_input(contents: &str) -> std::result::Result<Value, ParseError> {
+    Ok(serde_json::from_str(contents)?)
+}
+
+/// Sum of every number in the document.
+pub fn sum(json: &Value) -> i64 {
+    match json {
+        Value::Number(n) => n.as_i64().unwrap(),
+        Value::Array(j) => j.iter().map(sum).sum(),
+        Value::Object(j) => j.values().map(sum).sum(),
+        _ => 0,
+    }
+}
+
+/// Sum of every number in the document, ignoring any object (and its children) which has a
+/// property with the value "red".
+pub fn filtered_sum(json: &Value) -> i64 {
+    match json {
+        Value::Number(n) => n.as_i64().unwrap(),
+        Value::Array(j) => j.iter().map(filtered_sum).sum(),
+        Value::Object(j) => {
+            if j.values().any(|e| e == "red") {
+                0
+            }
+            else {
+                j.values().map(filtered_sum).sum()
+            }
+        },
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_of_numbers() {
+        let examples = [("[1,2,3]", 6), (r#"{"a":2,"b":4}"#, 6), ("[[[3]]]", 3),
+            (r#"{"a":{"b":4},"c":-1}"#, 3), (r#"{"a":[-1,1]}"#, 0), (r#"[-1,{"a":1}]"#, 0),
+            ("[]", 0), ("{}", 0)];
+
+        for &(document, expected) in &examples {
+            assert_eq!(sum(&read_input(document).unwrap()), expected);
+        }
+    }
+
+    #[test]
+    fn ignore_red() {
+        let examples = [("[1,2,3]", 6), (r#"[1,{"c":"red","b":2},3]"#, 4),
+            (r#"{"d":"red","e":[1,2,3,4],"f":5}"#, 0), (r#"[1,"red",5]"#, 6)];
+
+        for &(document, expected) in &examples {
+            assert_eq!(filtered_sum(&read_input(document).unwrap()), expected);
+        }
+    }
+}
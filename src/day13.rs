@@ -0,0 +1,152 @@
+//! Day 13: Knights of the Dinner Table (<https://adventofcode.com/2015/day/13>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use permutohedron::Heap;
+use std::collections::{HashMap, HashSet};
+
+/// Find the optimal seating arrangement around a circular table, given the happiness each person
+/// would gain or lose by sitting next to each other person.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let happinesses = read_input(input)?;
+        let mut persons = persons(&happinesses);
+        Ok(part1(&happinesses, &mut persons).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let happinesses = read_input(input)?;
+        let mut persons = persons(&happinesses);
+        Ok(part2(&happinesses, &mut persons).to_string())
+    }
+}
+
+/// Read the happiness changes (e.g. `Alice would gain 54 happiness units by sitting next to Bob.`).
+pub fn read_input(contents: &str) -> std::result::Result<HashMap<(String, String), i32>, ParseError> {
+    let mut result = HashMap::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let (persons, gain_loss) = read_line(line).map_err(|e| e.at_line(idx + 1))?;
+        result.insert(persons, gain_loss);
+    }
+
+    /* Anyone can sit next to anyone, so every pair of persons needs a happiness. */
+    let end = contents.lines().count() + 1;
+    let persons: HashSet<&String> = result.keys().flat_map(|(a, b)| vec![a, b]).collect();
+    if persons.is_empty() {
+        return Err(ParseError::missing("", "Missing happinesses.").at_line(end));
+    }
+    for person_a in &persons {
+        for person_b in persons.iter().filter(|&person_b| person_b != person_a) {
+            if !result.contains_key(&(person_a.to_string(), person_b.to_string())) {
+                let message = format!("Missing the happiness of {} next to {}.", person_a, person_b);
+                return Err(ParseError::missing("", &message).at_line(end));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn read_line(line: &str) -> std::result::Result<((String, String), i32), ParseError> {
+    let mut iter = line.split_whitespace();
+    let person_a = next_token(&mut iter, line, "person")?;
+    next_token(&mut iter, line, "`would`")?;
+    let gain_loss = match next_token(&mut iter, line, "`gain` or `lose`")? {
+        "gain" => parse_token::<i32>(line, next_token(&mut iter, line, "happiness")?)?,
+        "lose" => -parse_token::<i32>(line, next_token(&mut iter, line, "happiness")?)?,
+        s => return Err(ParseError::new(line, s, "Expected `gain` or `lose`.")),
+    };
+    let person_b = iter.last()
+        .ok_or_else(|| ParseError::missing(line, "Missing neighbor."))?
+        .trim_matches('.');
+
+    Ok(((person_a.to_string(), person_b.to_string()), gain_loss))
+}
+
+/// Every person attending the dinner.
+pub fn persons(happinesses: &HashMap<(String, String), i32>) -> Vec<String> {
+    happinesses.keys()
+            .map(|k| k.0.clone())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect::<Vec<String>>()
+}
+
+/// Total change in happiness for the optimal seating arrangement.
+pub fn part1(happinesses: &HashMap<(String, String), i32>, persons: &mut [String])
+        -> i32 {
+
+    Heap::new(persons).map(|p| {
+        let person_a = p.first().unwrap();
+        let person_b = p.last().unwrap();
+
+        happinesses[&(person_a.to_string(), person_b.to_string())]
+            + happinesses[&(person_b.to_string(), person_a.to_string())]
+            + p.windows(2).map(|w| {
+                let person_a = w.first().unwrap();
+                let person_b = w.last().unwrap();
+                happinesses[&(person_a.to_string(), person_b.to_string())]
+                    + happinesses[&(person_b.to_string(), person_a.to_string())]
+            }).sum::<i32>()
+    }).max().unwrap()
+}
+
+/// Total change in happiness for the optimal seating arrangement, including yourself (you are
+/// apathetic to everyone, and vice versa).
+pub fn part2(happinesses: &HashMap<(String, String), i32>, persons: &mut Vec<String>)
+        -> i32 {
+
+    /* Build new hashmap to add an apathetic (me!) person. */
+    let new_person = "Apathetic";
+    let mut happinesses = happinesses.clone();
+
+    persons.iter()
+        .for_each(|person| {
+            happinesses.insert((new_person.to_string(), person.clone()), 0);
+            happinesses.insert((person.clone(), new_person.to_string()), 0);
+        });
+
+    persons.push(new_person.to_string());
+
+    part1(&happinesses, persons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.
+";
+
+    #[test]
+    fn optimal_arrangement() {
+        let happinesses = read_input(EXAMPLE).unwrap();
+        let mut persons = persons(&happinesses);
+        assert_eq!(persons.len(), 4);
+        assert_eq!(part1(&happinesses, &mut persons), 330);
+    }
+
+    #[test]
+    fn missing_happiness() {
+        assert_eq!(read_input("").unwrap_err().message, "Missing happinesses.");
+        let error = read_input(EXAMPLE.lines().skip(1).collect::<Vec<_>>().join("\n").as_str()).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (12, "Missing the happiness of Alice next to Bob."));
+        let error = read_input("Alice would gain 54 happiness units by sitting next to Bob.").unwrap_err();
+        assert_eq!(error.message, "Missing the happiness of Bob next to Alice.");
+    }
+}
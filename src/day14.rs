@@ -0,0 +1,132 @@
+//! Day 14: Reindeer Olympics (<https://adventofcode.com/2015/day/14>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+
+const PUZZLE_INPUT: u32 = 2503;
+
+/// The reindeer Olympics: reindeer can fly at a given speed for a while, but must then rest.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let reindeers = read_input(input)?;
+        Ok(part1(&reindeers, PUZZLE_INPUT).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let reindeers = read_input(input)?;
+        Ok(part2(&reindeers, PUZZLE_INPUT).to_string())
+    }
+}
+
+/// Reindeer flying at `speed` (km/s) for `flight_time` seconds, then resting for `rest_time`
+/// seconds.
+#[derive(Hash, PartialEq, Eq)]
+pub struct Reindeer {
+    /// Name of the reindeer.
+    pub name: String,
+    /// Flying speed, in km/s.
+    pub speed: u32,
+    /// Number of seconds the reindeer can fly before resting.
+    pub flight_time: u32,
+    /// Number of seconds the reindeer must then rest.
+    pub rest_time: u32,
+}
+
+impl Reindeer {
+    /// Create a reindeer from its statistics.
+    pub fn new(name: &str, speed: u32, flight_time: u32, rest_time: u32) ->
+            Reindeer {
+        Reindeer{name: name.to_string(), speed, flight_time, rest_time}
+    }
+
+    /// Distance traveled after `time` seconds.
+    pub fn distance_traveled(&self, time: u32) -> u32 {
+        let mut elapsed = 0;
+        let mut distance = 0;
+
+        while elapsed < time {
+            distance += self.flight_time.min(time - elapsed) * self.speed;
+            elapsed += self.flight_time + self.rest_time;
+        }
+
+        distance
+    }
+}
+
+/// Read the reindeer descriptions (e.g. `Comet can fly 14 km/s for 10 seconds, but then must
+/// rest for 127 seconds.`).
+pub fn read_input(contents: &str) -> std::result::Result<Vec<Reindeer>, ParseError> {
+    let reindeers = contents.lines()
+        .enumerate()
+        .map(|(idx, line)| read_reindeer(line).map_err(|e| e.at_line(idx + 1)))
+        .collect::<std::result::Result<Vec<Reindeer>, ParseError>>()?;
+
+    if reindeers.is_empty() {
+        return Err(ParseError::missing("", "Missing reindeer."));
+    }
+    Ok(reindeers)
+}
+
+fn read_reindeer(line: &str) -> std::result::Result<Reindeer, ParseError> {
+    let mut iter = line.split_whitespace();
+    let name = next_token(&mut iter, line, "name")?;
+    let speed = parse_token(line, next_token(&mut iter.by_ref().skip(2), line, "speed")?)?;
+    let flight_time = parse_token(line, next_token(&mut iter.by_ref().skip(2), line, "flight time")?)?;
+    let rest_time = parse_token(line, next_token(&mut iter.by_ref().skip(6), line, "rest time")?)?;
+
+    Ok(Reindeer::new(name, speed, flight_time, rest_time))
+}
+
+/// Distance traveled by the winning reindeer after `time` seconds.
+pub fn part1(reindeers: &[Reindeer], time: u32) -> u32 {
+    reindeers.iter()
+        .map(|reindeer| reindeer.distance_traveled(time)).max().unwrap()
+}
+
+/// Points of the winning reindeer after `time` seconds, where every second, the reindeer in the
+/// lead get a point.
+pub fn part2(reindeers: &[Reindeer], time: u32) -> u32 {
+    let mut scores = vec![0;reindeers.len()];
+
+    for t in 1..time+1 {
+        let distances: Vec<u32> = reindeers.iter()
+                .map(|reindeer| reindeer.distance_traveled(t))
+                .collect();
+
+        let max_distance = distances.iter().max().unwrap();
+
+        distances.iter()
+            .zip(scores.iter_mut())
+            .for_each(|(d, s)| if d == max_distance {*s +=1;});
+    }
+
+    scores.into_iter().max().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.
+";
+
+    #[test]
+    fn distance_after_1000_seconds() {
+        let reindeers = read_input(EXAMPLE).unwrap();
+        assert_eq!(reindeers[0].distance_traveled(1), 14);
+        assert_eq!(reindeers[1].distance_traveled(10), 160);
+        assert_eq!(reindeers[0].distance_traveled(1000), 1120);
+        assert_eq!(reindeers[1].distance_traveled(1000), 1056);
+        assert_eq!(part1(&reindeers, 1000), 1120);
+    }
+
+    #[test]
+    fn points_after_1000_seconds() {
+        let reindeers = read_input(EXAMPLE).unwrap();
+        assert_eq!(part2(&reindeers, 1000), 689);
+    }
+}
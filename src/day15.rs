@@ -0,0 +1,209 @@
+//! Day 15: Science for Hungry People (<https://adventofcode.com/2015/day/15>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::str::FromStr;
+
+const PUZZLE_INPUT: i32 = 100;
+const MAX_CALORIES: i32 = 500;
+
+/// Find the highest-scoring cookie recipe, using exactly 100 teaspoons of ingredients.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let cookie_recipe = read_input(input)?;
+        Ok(cookie_recipe.map(|c| c.score()).max()
+            .ok_or("No recipe uses exactly 100 teaspoons.")?
+            .to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let cookie_recipe = read_input(input)?;
+        Ok(cookie_recipe
+            .filter_map(|c|
+                if c.calories() <= MAX_CALORIES {Some(c.score())} else {None})
+            .max()
+            .ok_or("No recipe uses exactly 100 teaspoons with at most 500 calories.")?
+            .to_string())
+    }
+}
+
+/// Properties of an ingredient, per teaspoon.
+#[derive(Clone, Debug)]
+pub struct Ingredient {
+    /// Name of the ingredient.
+    pub name: String,
+    /// Change in capacity of the cookie, per teaspoon.
+    pub capacity: i32,
+    /// Change in durability of the cookie, per teaspoon.
+    pub durability: i32,
+    /// Change in flavor of the cookie, per teaspoon.
+    pub flavor: i32,
+    /// Change in texture of the cookie, per teaspoon.
+    pub texture: i32,
+    /// Calories per teaspoon.
+    pub calories: i32,
+}
+
+/// Read an ingredient (e.g. `Butterscotch: capacity -1, durability -2, flavor 6, texture 3,
+/// calories 8`).
+impl FromStr for Ingredient {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Ingredient, ParseError> {
+        let mut iter = s.splitn(2, ": ");
+        let name = next_token(&mut iter, s, "name")?;
+        let mut properties = next_token(&mut iter, s, "properties")?.split(", ");
+
+        let mut property = |expected: &str| {
+            let mut iter = next_token(&mut properties, s, expected)?.split(' ');
+            let name = next_token(&mut iter, s, expected)?;
+            if name != expected {
+                return Err(ParseError::new(s, name, &format!("Expected {}.", expected)));
+            }
+            parse_token(s, next_token(&mut iter, s, expected)?)
+        };
+
+        Ok(Ingredient {
+            name: name.to_string(),
+            capacity: property("capacity")?,
+            durability: property("durability")?,
+            flavor: property("flavor")?,
+            texture: property("texture")?,
+            calories: property("calories")?,
+        })
+    }
+}
+
+/// Quantity of every ingredient in a recipe. Iterating goes through every recipe using exactly
+/// `teaspoons` teaspoons of ingredients.
+#[derive(Clone)]
+pub struct CookieRecipe {
+    ingredients: Vec<(Ingredient, i32)>,
+    teaspoons: i32,
+}
+
+impl CookieRecipe {
+    /// Create a recipe with none of the ingredients.
+    pub fn from_ingredients(ingredients: &[Ingredient], teaspoons: i32)
+            -> CookieRecipe {
+        CookieRecipe {
+            ingredients: ingredients.iter().map(|i| (i.clone(), 0)).collect(),
+            teaspoons,
+        }
+    }
+
+    /// Total capacity of the cookie, which is at least 0.
+    pub fn capacity(&self) -> i32 {
+        self.ingredients.iter()
+            .map(|(i, qty)| qty * i.capacity)
+            .sum::<i32>()
+            .max(0)
+    }
+
+    /// Total durability of the cookie, which is at least 0.
+    pub fn durability(&self) -> i32 {
+        self.ingredients.iter()
+            .map(|(i, qty)| qty * i.durability)
+            .sum::<i32>()
+            .max(0)
+    }
+
+    /// Total flavor of the cookie, which is at least 0.
+    pub fn flavor(&self) -> i32 {
+        self.ingredients.iter()
+            .map(|(i, qty)| qty * i.flavor)
+            .sum::<i32>()
+            .max(0)
+    }
+
+    /// Total texture of the cookie, which is at least 0.
+    pub fn texture(&self) -> i32 {
+        self.ingredients.iter()
+            .map(|(i, qty)| qty * i.texture)
+            .sum::<i32>()
+            .max(0)
+    }
+
+    /// Total calories of the cookie.
+    pub fn calories(&self) -> i32 {
+        self.ingredients.iter()
+            .map(|(i, qty)| qty * i.calories)
+            .sum::<i32>()
+    }
+
+    /// Total score of the cookie, which is the product of its properties (excluding calories).
+    pub fn score(&self) -> i32 {
+        self.capacity() * self.durability() * self.flavor() * self.texture()
+    }
+
+    fn increase_quantity(&mut self) -> Option<()> {
+        for (_, q) in self.ingredients.iter_mut() {
+            if *q == self.teaspoons {
+                *q = 0;
+            } else {
+                *q += 1;
+                return Some(());
+            }
+        }
+
+        None
+    }
+}
+
+impl Iterator for CookieRecipe {
+    type Item = CookieRecipe;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.increase_quantity()?;
+
+        while self.ingredients.iter().map(|(_, q)| q).sum::<i32>() != self.teaspoons {
+            self.increase_quantity()?;
+        }
+
+        Some(self.clone())
+    }
+}
+
+/// Read the ingredients, one per line, into an empty recipe.
+pub fn read_input(contents: &str) -> std::result::Result<CookieRecipe, ParseError> {
+    let ingredients = contents.lines()
+        .enumerate()
+        .map(|(idx, line)| line.parse().map_err(|e: ParseError| e.at_line(idx + 1)))
+        .collect::<std::result::Result<Vec<Ingredient>, ParseError>>()?;
+
+    if ingredients.is_empty() {
+        return Err(ParseError::missing("", "Missing ingredients."));
+    }
+    Ok(CookieRecipe::from_ingredients(&ingredients, PUZZLE_INPUT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3
+";
+
+    #[test]
+    fn best_score() {
+        let recipe = read_input(EXAMPLE).unwrap();
+        let best = CookieRecipe {
+            ingredients: vec![(recipe.ingredients[0].0.clone(), 44), (recipe.ingredients[1].0.clone(), 56)],
+            teaspoons: PUZZLE_INPUT,
+        };
+
+        assert_eq!(best.score(), 62842880);
+        assert_eq!(recipe.map(|c| c.score()).max(), Some(62842880));
+    }
+
+    #[test]
+    fn best_score_with_500_calories() {
+        let recipe = read_input(EXAMPLE).unwrap();
+        let best = recipe.filter(|c| c.calories() == MAX_CALORIES).map(|c| c.score()).max();
+        assert_eq!(best, Some(57600000));
+    }
+}
@@ -0,0 +1,146 @@
+//! Day 16: Aunt Sue (<https://adventofcode.com/2015/day/16>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::collections::HashMap;
+
+/// Aunt Sue sent a gift, but which one of the 500 aunts Sue is it? The MFCSAM analysed the gift
+/// and detected a sample of compounds, which must match what is remembered of each aunt.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let aunts = read_input(input)?;
+        aunts.into_iter()
+            .find(|aunt| aunt.compare(sample))
+            .map(|aunt| aunt.name)
+            .ok_or_else(|| "No matching aunt.".into())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let aunts = read_input(input)?;
+        aunts.into_iter()
+            .find(|aunt| aunt.compare2(sample))
+            .map(|aunt| aunt.name)
+            .ok_or_else(|| "No matching aunt.".into())
+    }
+}
+
+/// What is remembered of an aunt: her name and the amounts of some compounds.
+#[derive(Debug, Clone)]
+pub struct Aunt {
+    /// Name of the aunt (e.g. `Sue 40`).
+    pub name: String,
+    /// Amount of each compound remembered, by name.
+    pub sample: HashMap<String, u32>,
+}
+
+impl Default for Aunt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Aunt {
+    /// Create an aunt with no name and nothing remembered.
+    pub fn new() -> Aunt {
+        Aunt { name: String::new(), sample: HashMap::new() }
+    }
+
+    /// Set the name of the aunt.
+    pub fn add_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    /// Add the amount of a compound detected by the MFCSAM.
+    pub fn add_compound(&mut self, compound: &str, amount: u32) -> std::result::Result<(), ParseError> {
+        match compound {
+            "children" | "cats" | "samoyeds" | "pomeranians" | "akitas" |
+            "vizslas" | "goldfish" | "trees" | "cars" |"perfumes"
+                => {self.sample.insert(compound.to_string(), amount);},
+            _ => return Err(ParseError::new(compound, compound, "Compound not allowed.")),
+        }
+
+        Ok(())
+    }
+
+    /// Check whether every compound remembered matches the value given by `comparison_function`.
+    pub fn compare<F>(&self, comparison_function: F) -> bool
+        where F: Fn(&str) -> u32 {
+
+        self.sample.iter()
+            .all(|(k, v)| *v == comparison_function(k))
+    }
+
+    /// Same as `compare`, but updated for the outdated retroencabulator: there must be more cats
+    /// and trees, and fewer pomeranians and goldfish, than the amounts given by
+    /// `comparison_function`.
+    pub fn compare2<F>(&self, comparison_function: F) -> bool
+        where F: Fn(&str) -> u32 {
+
+        self.sample.iter()
+            .all(|(k, v)| match k.as_str() {
+                "cats" | "trees" => *v > comparison_function(k),
+                "pomeranians" | "goldfish" => *v < comparison_function(k),
+                _ => *v == comparison_function(k),
+            })
+    }
+}
+
+/// Read the aunts (e.g. `Sue 1: goldfish: 9, cars: 0, samoyeds: 9`).
+pub fn read_input(contents: &str) -> std::result::Result<Vec<Aunt>, ParseError> {
+    contents.lines()
+        .enumerate()
+        .map(|(idx, line)| read_aunt(line).map_err(|e| e.at_line(idx + 1)))
+        .collect()
+}
+
+fn read_aunt(line: &str) -> std::result::Result<Aunt, ParseError> {
+    let mut aunt = Aunt::new();
+
+    let mut iter = line.splitn(2, ": ");
+    aunt.add_name(next_token(&mut iter, line, "name")?);
+
+    for compound in next_token(&mut iter, line, "compounds")?.split(", ") {
+        let mut name_amount = compound.split(": ");
+        let name = next_token(&mut name_amount, line, "compound")?;
+        let amount = parse_token(line, next_token(&mut name_amount, line, "amount")?)?;
+        aunt.add_compound(name, amount).map_err(|e| e.within(line, name))?;
+    }
+
+    Ok(aunt)
+}
+
+/// Amount of a compound in the gift, as given in the problem definition.
+///
+/// Can't build a const HashMap (yet!), so one can use a function to check against the sample.
+pub fn sample(compound: &str) -> u32 {
+    match compound {
+        "children" => 3,
+        "cats" => 7,
+        "samoyeds" => 2,
+        "pomeranians" => 3,
+        "akitas" => 0,
+        "vizslas" => 0,
+        "goldfish" => 5,
+        "trees" => 3,
+        "cars" => 2,
+        "perfumes" => 1,
+        _ => panic!("Unknown compound."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_with_sample() {
+        let aunts = read_input("Sue 1: cats: 7, trees: 3, cars: 2\nSue 2: cats: 8, goldfish: 4, cars: 2\n").unwrap();
+
+        assert!(aunts[0].compare(sample));
+        assert!(!aunts[0].compare2(sample));
+        assert!(!aunts[1].compare(sample));
+        assert!(aunts[1].compare2(sample));
+    }
+}
@@ -0,0 +1,85 @@
+//! Day 17: No Such Thing as Too Much (<https://adventofcode.com/2015/day/17>).
+
+use crate::error::{parse_token, ParseError};
+use crate::solver::{Result, Solver};
+
+const PUZZLE_INPUT: usize = 150;
+
+/// The elves bought too much eggnog, which must be stored in containers holding exactly 150
+/// liters.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let containers = read_input(input)?;
+        Ok(count_combinations(&containers, PUZZLE_INPUT).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let containers = read_input(input)?;
+        Ok(count_combinations_part2(&containers, PUZZLE_INPUT).to_string())
+    }
+}
+
+/// Read the capacity of the containers, one per line.
+pub fn read_input(contents: &str) -> std::result::Result<Vec<usize>, ParseError> {
+    let containers = contents.lines()
+        .enumerate()
+        .map(|(idx, line)| parse_token(line, line).map_err(|e| e.at_line(idx + 1)))
+        .collect::<std::result::Result<Vec<usize>, ParseError>>()?;
+
+    if containers.is_empty() {
+        return Err(ParseError::missing("", "Missing containers."));
+    }
+    Ok(containers)
+}
+
+/// Number of combinations of containers which can exactly fit `amount` liters.
+pub fn count_combinations(containers: &[usize], amount: usize) -> usize {
+    let max_nbr = 1_usize << containers.len();
+
+    /* Use a yes/no vector (usize). */
+    (0..max_nbr).filter(|x|
+        containers.iter()
+            .enumerate()
+            .map(|(idx, c)| c * ((x >> idx) & 1))
+            .sum::<usize>() == amount)
+        .count()
+}
+
+/// Number of combinations of containers which can exactly fit `amount` liters, using the smallest
+/// number of containers.
+pub fn count_combinations_part2(containers: &[usize], amount: usize) -> usize {
+    let max_nbr = 1_usize << containers.len();
+
+    /* Use a yes/no vector (usize). */
+    let nbr_containers: Vec<usize> = (0..max_nbr).filter_map(|x|
+        if containers.iter()
+                    .enumerate()
+                    .map(|(idx, c)| c * ((x >> idx) & 1))
+                    .sum::<usize>() == amount {
+            Some(x.count_ones() as usize)
+        }
+        else {
+            None
+        })
+        .collect();
+
+    match nbr_containers.iter().min() {
+        Some(min_nbr_containers) => nbr_containers.iter().filter(|&n| n == min_nbr_containers).count(),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_25_liters() {
+        let containers = read_input("20\n15\n10\n5\n5\n").unwrap();
+        assert_eq!(count_combinations(&containers, 25), 4);
+        assert_eq!(count_combinations_part2(&containers, 25), 3);
+        assert_eq!(count_combinations_part2(&[], 25), 0);
+    }
+}
@@ -0,0 +1,148 @@
+//! Day 18: Like a GIF For Your Yard (<https://adventofcode.com/2015/day/18>).
+
+use crate::error::ParseError;
+use crate::solver::{Result, Solver};
+use itertools::Itertools;
+
+const PUZZLE_INPUT: usize = 100;
+
+/// Animated lights, in the spirit of Conway's Game of Life.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let mut lightgrid = LightGrid::from_input(input)?;
+        lightgrid.steps(PUZZLE_INPUT);
+        Ok(lightgrid.nbr_lights_on().to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let mut lightgrid = LightGrid::from_input(input)?;
+        lightgrid.sticky_lights();
+        lightgrid.steps_part2(PUZZLE_INPUT);
+        Ok(lightgrid.nbr_lights_on().to_string())
+    }
+}
+
+/// Grid of lights, where `#` is on and `.` is off.
+pub struct LightGrid(pub Vec<Vec<bool>>);
+
+impl LightGrid {
+    /// Read the initial configuration of the lights.
+    pub fn from_input(contents: &str) -> std::result::Result<LightGrid, ParseError> {
+        let mut grid = Vec::new();
+
+        for (idx, line) in contents.lines().enumerate() {
+            if let Some(pos) = line.find(|c| c != '#' && c != '.') {
+                let token = &line[pos..pos + line[pos..].chars().next().unwrap().len_utf8()];
+                return Err(ParseError::new(line, token, "Expected `#` or `.`.").at_line(idx + 1));
+            }
+
+            /* Every line must be as wide as the first one. */
+            if grid.first().is_some_and(|first: &Vec<bool>| first.len() != line.len()) || line.is_empty() {
+                return Err(ParseError::new(line, line, "Inconsistent grid width.").at_line(idx + 1));
+            }
+
+            grid.push(line.chars().map(|c| c == '#').collect());
+        }
+
+        if grid.is_empty() {
+            return Err(ParseError::missing("", "Empty grid."));
+        }
+
+        Ok(LightGrid(grid))
+    }
+
+    /// Count the lights which are on.
+    pub fn nbr_lights_on(&self) -> usize {
+        self.0.iter()
+            .flat_map(|line| line.iter().filter(|&&b| b))
+            .count()
+    }
+
+    /// Animate the lights once: a light which is on stays on when 2 or 3 neighbors are on, and a
+    /// light which is off turns on if exactly 3 neighbors are on.
+    pub fn step(&mut self) {
+        let mut new_grid: Vec<Vec<bool>> = Vec::new();
+
+        let max_y = self.0.len();
+        let max_x = self.0[0].len();
+
+        for y in 0..max_y {
+            let mut new_line: Vec<bool> = Vec::new();
+            for x in 0..max_x {
+                let neighbors_on = (y.saturating_sub(1)..max_y.min(y+2))
+                    .cartesian_product(x.saturating_sub(1)..max_x.min(x+2))
+                    .filter(|&(y, x)| self.0[y][x])
+                    .count();
+
+                new_line.push(
+                    if self.0[y][x] {
+                        neighbors_on == 3 || neighbors_on == 4
+                    }
+                    else {
+                        neighbors_on == 3
+                    }
+                );
+            }
+
+            new_grid.push(new_line);
+        }
+
+        self.0 = new_grid;
+    }
+
+    /// Animate the lights `nbr_steps` times.
+    pub fn steps(&mut self, nbr_steps: usize) {
+        for _ in 0..nbr_steps {
+            self.step();
+        }
+    }
+
+    /// Turn on the four corners, which are stuck on.
+    pub fn sticky_lights(&mut self) {
+        /* Make sure the four corners are on. */
+        let dim_y = self.0.len() - 1;
+        let dim_x = self.0[0].len() - 1;
+        self.0[0][0] = true;
+        self.0[0][dim_x] = true;
+        self.0[dim_y][0] = true;
+        self.0[dim_y][dim_x] = true;
+    }
+
+    /// Animate the lights once, with the four corners stuck on.
+    pub fn step_part2(&mut self) {
+        self.step();
+        self.sticky_lights();
+    }
+
+    /// Animate the lights `nbr_steps` times, with the four corners stuck on.
+    pub fn steps_part2(&mut self, nbr_steps: usize) {
+        for _ in 0..nbr_steps {
+            self.step_part2();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..\n";
+
+    #[test]
+    fn animation() {
+        let mut grid = LightGrid::from_input(EXAMPLE).unwrap();
+        assert_eq!(grid.nbr_lights_on(), 15);
+        grid.steps(4);
+        assert_eq!(grid.nbr_lights_on(), 4);
+    }
+
+    #[test]
+    fn animation_with_stuck_corners() {
+        let mut grid = LightGrid::from_input(EXAMPLE).unwrap();
+        grid.sticky_lights();
+        grid.steps_part2(5);
+        assert_eq!(grid.nbr_lights_on(), 17);
+    }
+}
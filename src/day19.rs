@@ -0,0 +1,225 @@
+//! Day 19: Medicine for Rudolph (<https://adventofcode.com/2015/day/19>).
+
+use crate::elements::*;
+use crate::error::{next_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::collections::{HashMap, HashSet};
+
+/// Rudolph needs medicine, which is made by a machine performing replacements on molecules. The
+/// puzzle input lists the replacements, followed by an empty line and the medicine molecule.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let (transmogrifier, molecule) = read_input(input)?;
+        Ok(transmogrifier.transmogrify(&molecule).len().to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let (transmogrifier, molecule) = read_input(input)?;
+
+        /* Hoping that either one of the greedy reducers work. */
+        let answer = match transmogrifier.reduce_greedy(&molecule) {
+            Some(x) => x,
+            None => transmogrifier.reduce_greedy_rev(&molecule).ok_or("The molecule can't be reduced to `e`.")?,
+        };
+
+        Ok(answer.to_string())
+    }
+}
+
+/// A molecule is a sequence of elements, where `None` stands for the electron (`e`).
+pub type Molecule = Vec<Option<Element>>;
+
+/// Red-Nosed Reindeer nuclear fusion/fission plant, storing the possible replacements both ways.
+pub struct Transmogrifier {
+    /// Molecules which can replace each element (or the electron).
+    pub replacements: HashMap<Option<Element>, Vec<Molecule>>,
+    /// Element (or electron) which each molecule can be turned back into.
+    pub reverse: HashMap<Molecule, Option<Element>>,
+}
+
+impl Default for Transmogrifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transmogrifier {
+    /// Create a plant with no replacements.
+    pub fn new() -> Transmogrifier {
+        Transmogrifier {
+            replacements: HashMap::new(),
+            reverse: HashMap::new(),
+        }
+    }
+
+    /// Add a replacement of `input` (an element) by `output` (a molecule).
+    pub fn add_replacement(&mut self, input: Option<Element>, output: Molecule) {
+        self.replacements.entry(input.clone())
+            .or_default()
+            .push(output.clone());
+
+        self.reverse.insert(output, input);
+    }
+
+    /// Convert a symbol to an element, where `e` is the electron (`None`).
+    pub fn to_element(symbol: &str) -> std::result::Result<Option<Element>, ParseError> {
+        match symbol_to_element(symbol) {
+            None if symbol != "e" => Err(ParseError::new(symbol, symbol, "Unknown element.")),
+            element => Ok(element),
+        }
+    }
+
+    /// Split a molecule into its elements (e.g. `HOH` into hydrogen, oxygen and hydrogen).
+    pub fn to_elements(molecule: &str) -> std::result::Result<Molecule, ParseError> {
+        let mut elements = Vec::new();
+        let mut start = 0;
+
+        for (idx, x) in molecule.char_indices() {
+            if x.is_uppercase() && idx > start {
+                let symbol = &molecule[start..idx];
+                elements.push(Transmogrifier::to_element(symbol).map_err(|e| e.within(molecule, symbol))?);
+                start = idx;
+            }
+        }
+
+        /* Grab the last element. */
+        let symbol = &molecule[start..];
+        elements.push(Transmogrifier::to_element(symbol).map_err(|e| e.within(molecule, symbol))?);
+
+        Ok(elements)
+    }
+
+    /// Every distinct molecule which can be created by doing a single replacement.
+    pub fn transmogrify(&self, molecule: &[Option<Element>]) -> HashSet<Molecule> {
+        let mut output = HashSet::new();
+
+        for idx in 0..molecule.len() {
+            if let Some(possibilities) = self.replacements.get(&molecule[idx]) {
+                for p in possibilities {
+                    let mut new_molecule = molecule.to_vec();
+                    new_molecule.splice(idx..idx+1, p.iter().cloned());
+                    output.insert(new_molecule);
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Every molecule which can be created by undoing a single replacement.
+    pub fn reduce(&self, molecule: &[Option<Element>]) -> Vec<Molecule> {
+        let mut output = Vec::new();
+
+        for idx in 0..molecule.len() {
+            for (k, v) in self.reverse.iter() {
+                if molecule[idx..].starts_with(k) {
+                    let mut new_molecule = molecule.to_vec();
+                    new_molecule.splice(idx..idx+k.len(), [v.clone()].iter().cloned());
+                    output.push(new_molecule);
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Number of steps to reduce the molecule to an electron, undoing the first replacement found
+    /// at the start of the molecule.
+    pub fn reduce_greedy(&self, molecule: &[Option<Element>]) -> Option<usize> {
+        if molecule == [None] {
+            return Some(0)
+        }
+
+        for idx in 0..molecule.len() {
+            for (k, v) in self.reverse.iter() {
+                if molecule[0..].starts_with(k) {
+                    let mut new_molecule = molecule.to_vec();
+                    new_molecule.splice(idx..idx+k.len(), [v.clone()].iter().cloned());
+                    return self.reduce_greedy(&new_molecule).map(|x| 1 + x);
+                }
+            }
+        }
+        None
+    }
+
+    /// Number of steps to reduce the molecule to an electron, undoing the replacement found closest
+    /// to the end of the molecule.
+    pub fn reduce_greedy_rev(&self, molecule: &[Option<Element>]) -> Option<usize> {
+        if molecule == [None] {
+            return Some(0)
+        }
+
+        let mut new_molecule = molecule.to_vec();
+
+        for idx in (0..molecule.len()).rev() {
+            for (k, v) in self.reverse.iter() {
+                if molecule[idx..].starts_with(k) {
+                    new_molecule.splice(idx..idx+k.len(), [v.clone()].iter().cloned());
+                    return self.reduce_greedy_rev(&new_molecule).map(|x| 1 + x);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Read the replacements (e.g. `H => HO`) and the medicine molecule.
+pub fn read_input(contents: &str) -> std::result::Result<(Transmogrifier, Molecule), ParseError> {
+    let mut transmogrifier = Transmogrifier::new();
+    let mut lines = contents.lines().enumerate();
+
+    for (idx, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+        let (input, output) = read_replacement(line).map_err(|e| e.at_line(idx + 1))?;
+        transmogrifier.add_replacement(input, output);
+    }
+
+    let (idx, molecule) = lines.next().ok_or_else(|| ParseError::missing("", "Missing molecule."))?;
+    let molecule = Transmogrifier::to_elements(molecule.trim()).map_err(|e| e.at_line(idx + 1))?;
+    Ok((transmogrifier, molecule))
+}
+
+/* Read a replacement (e.g. `H => HO`). */
+fn read_replacement(line: &str) -> std::result::Result<(Option<Element>, Molecule), ParseError> {
+    let mut iter = line.split(" => ");
+    let a = next_token(&mut iter, line, "element")?;
+    let b = next_token(&mut iter, line, "`=>`")?;
+
+    Ok((Transmogrifier::to_element(a).map_err(|e| e.within(line, a))?,
+        Transmogrifier::to_elements(b).map_err(|e| e.within(line, b))?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPLACEMENTS: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n";
+
+    #[test]
+    fn distinct_molecules() {
+        let (transmogrifier, molecule) = read_input(&format!("{}\nHOH\n", REPLACEMENTS)).unwrap();
+        assert_eq!(transmogrifier.transmogrify(&molecule).len(), 4);
+
+        let molecule = Transmogrifier::to_elements("HOHOHO").unwrap();
+        assert_eq!(transmogrifier.transmogrify(&molecule).len(), 7);
+    }
+
+    /* The greedy reducers rely on the shape of the actual replacements (no
+     * ambiguity), and fail on the example, so only single steps are checked. */
+    #[test]
+    fn undo_replacement() {
+        let (transmogrifier, molecule) = read_input(&format!("{}\nHOH\n", REPLACEMENTS)).unwrap();
+        let reduced = transmogrifier.reduce(&molecule);
+
+        assert!(reduced.contains(&Transmogrifier::to_elements("HH").unwrap()));
+        assert!(reduced.contains(&Transmogrifier::to_elements("eOH").unwrap()));
+        assert!(transmogrifier.reduce(&[Transmogrifier::to_element("H").unwrap()]).contains(&vec![None]));
+    }
+
+    #[test]
+    fn irreducible_molecule() {
+        let error = Puzzle.part2("e => H\nH => HO\n\nOH\n").unwrap_err();
+        assert_eq!(error.to_string(), "The molecule can't be reduced to `e`.");
+    }
+}
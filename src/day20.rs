@@ -0,0 +1,72 @@
+//! Day 20: Infinite Elves and Infinite Houses (<https://adventofcode.com/2015/day/20>).
+
+use crate::error::parse_token;
+use crate::solver::{Result, Solver};
+
+/// An infinite number of elves deliver presents to an infinite number of houses: elf `n` delivers
+/// `10 * n` presents to every house whose number is a multiple of `n`.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let puzzle_input: u32 = parse_token(input.trim(), input.trim())?;
+        let answer = (1..).find(|&x| delivered_presents(x) >= puzzle_input).unwrap();
+        Ok(answer.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let puzzle_input: u32 = parse_token(input.trim(), input.trim())?;
+        let answer = (1..)
+            .find(|&x| delivered_presents_part2(x) >= puzzle_input).unwrap();
+        Ok(answer.to_string())
+    }
+}
+
+/// Number of presents delivered to a house.
+pub fn delivered_presents(house_number: u32) -> u32 {
+    let end = (house_number as f64).sqrt() as u32 + 1;
+    ((1..end).map(|x| {
+        match (house_number % x, house_number / x) {
+            (0, y) if y == x => x,
+            (0, y) => x + y,
+            _ => 0,
+        }})
+        .sum::<u32>()) * 10
+}
+
+/// Number of presents delivered to a house, where every elf now stops after 50 houses, but
+/// delivers `11 * n` presents.
+pub fn delivered_presents_part2(house_number: u32) -> u32 {
+    let end = (house_number as f64).sqrt() as u32 + 1;
+
+    (1..end).map(|x| {
+        match (house_number % x, house_number / x, x) {
+            (0, a @ 1 ..= 50, b @ 1 ..= 50) =>
+                if a == b { a } else { a + b },
+            (0, 1 ..= 50, b) => b,
+            (0, a, 1 ..= 50) => a,
+            _ => 0,
+        }})
+        .sum::<u32>() * 11
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presents_per_house() {
+        let expected = [10, 30, 40, 70, 60, 120, 80, 150, 130];
+
+        for (house, &presents) in (1..).zip(expected.iter()) {
+            assert_eq!(delivered_presents(house), presents);
+        }
+    }
+
+    #[test]
+    fn lazy_elves() {
+        assert_eq!(delivered_presents_part2(6), 6 * 11 * 2);
+        /* Elf 1 stopped after house 50. */
+        assert_eq!(delivered_presents_part2(51), (51 + 17 + 3) * 11);
+    }
+}
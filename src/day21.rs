@@ -0,0 +1,231 @@
+//! Day 21: RPG Simulator 20XX (<https://adventofcode.com/2015/day/21>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+
+const PLAYER_HP: usize = 100;
+
+/* The item shop, as given in the problem definition. Requires removing the
+ * space in "Damage +1" et al. */
+const STORE: &str = "\
+Weapons:    Cost  Damage  Armor
+Dagger        8     4       0
+Shortsword   10     5       0
+Warhammer    25     6       0
+Longsword    40     7       0
+Greataxe     74     8       0
+
+Armor:      Cost  Damage  Armor
+Leather      13     0       1
+Chainmail    31     0       2
+Splintmail   53     0       3
+Bandedmail   75     0       4
+Platemail   102     0       5
+
+Rings:      Cost  Damage  Armor
+Damage+1     25     1       0
+Damage+2     50     2       0
+Damage+3    100     3       0
+Defense+1    20     0       1
+Defense+2    40     0       2
+Defense+3    80     0       3
+";
+
+/// Little Henry Case got a new video game, where the player must buy equipment (one weapon, up to
+/// one armor and up to two rings) to defeat the boss.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let (wins, _losses) = simulate_battles(input)?;
+        Ok(wins.into_iter().min().ok_or("No equipment wins against the boss.")?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let (_wins, losses) = simulate_battles(input)?;
+        Ok(losses.into_iter().max().ok_or("No equipment loses against the boss.")?.to_string())
+    }
+}
+
+/// Weapons, armors and rings sold at the shop.
+pub type Store = (Vec<Equipment>, Vec<Equipment>, Vec<Equipment>);
+
+/// Item sold at the shop.
+#[derive(PartialEq)]
+pub struct Equipment {
+    /// Name of the item.
+    pub name: String,
+    /// Cost of the item, in gold.
+    pub cost: usize,
+    /// Damage added by the item.
+    pub damage: usize,
+    /// Armor added by the item.
+    pub armor: usize,
+}
+
+impl Equipment {
+    /// Create an item from its name and statistics.
+    pub fn new(name: &str, cost: usize, damage: usize, armor: usize) -> Equipment {
+        Equipment { name: name.to_string(), cost, damage, armor }
+    }
+}
+
+/// Player or boss.
+#[derive(Debug)]
+pub struct Unit {
+    /// Hit points.
+    pub hp: usize,
+    /// Damage dealt by each attack, before armor.
+    pub damage: usize,
+    /// Armor, reducing the damage of the attacks received.
+    pub armor: usize,
+}
+
+impl Unit {
+    /// Create a unit from its statistics.
+    pub fn new(hp: usize, damage: usize, armor: usize) -> Unit {
+        Unit { hp, damage, armor }
+    }
+
+    /// Check whether this unit (attacking first) wins the fight against `other`.
+    pub fn simulate_battle(&self, other: &Unit) -> bool {
+        let mut hp1 = self.hp;
+        let mut hp2 = other.hp;
+
+        let damage1 = 1.max(self.damage.saturating_sub(other.armor));
+        let damage2 = 1.max(other.damage.saturating_sub(self.armor));
+
+        loop {
+            hp2 = hp2.saturating_sub(damage1);
+            if hp2 == 0 {
+                return true;
+            }
+            hp1 = hp1.saturating_sub(damage2);
+            if hp1 == 0 {
+                return false;
+            }
+        }
+    }
+}
+
+/// Split the costs of every possible equipment between wins and losses against the boss.
+pub fn simulate_battles(contents: &str) -> std::result::Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let boss = read_boss_file(contents)?;
+    let (weapons, armors, rings) = read_store_file(STORE)?;
+
+    let units = generate_units(PLAYER_HP, &weapons, &armors, &rings);
+
+    let (wins, losses): (Vec<_>, Vec<_>) =
+        units.into_iter().partition(|(_cost, unit)|
+            unit.simulate_battle(&boss));
+
+    Ok((wins.into_iter().map(|(cost, _unit)| cost).collect(),
+        losses.into_iter().map(|(cost, _unit)| cost).collect()))
+}
+
+/// Read the boss statistics (hit points, damage and armor).
+pub fn read_boss_file(contents: &str) -> std::result::Result<Unit, ParseError> {
+    let mut iter = contents.lines();
+
+    Ok(Unit::new(
+        read_statistic(iter.next(), 1, "Hit Points")?,
+        read_statistic(iter.next(), 2, "Damage")?,
+        read_statistic(iter.next(), 3, "Armor")?,
+    ))
+}
+
+/* Read a line of the boss statistics (e.g. `Hit Points: 109`). */
+fn read_statistic(line: Option<&str>, line_number: usize, name: &str)
+        -> std::result::Result<usize, ParseError> {
+    let line = line.ok_or_else(||
+        ParseError::missing("", &format!("Missing {}.", name)).at_line(line_number))?;
+    let mut iter = line.split(": ");
+
+    match next_token(&mut iter, line, name) {
+        Ok(s) if s == name => (),
+        Ok(s) => return Err(ParseError::new(line, s, &format!("Expected {}.", name)).at_line(line_number)),
+        Err(e) => return Err(e.at_line(line_number)),
+    }
+
+    next_token(&mut iter, line, name)
+        .and_then(|token| parse_token(line, token))
+        .map_err(|e| e.at_line(line_number))
+}
+
+/// Read the weapons, armors and rings sold at the shop. A `None` armor and ring is added, since
+/// those are optional.
+pub fn read_store_file(contents: &str) -> std::result::Result<Store, ParseError> {
+    let mut weapons: Vec<Equipment> = Vec::new();
+    let mut armors: Vec<Equipment> = Vec::new();
+    let mut rings: Vec<Equipment> = Vec::new();
+    let mut current = &mut weapons;
+
+    for (idx, line) in contents.lines().enumerate() {
+        if line.is_empty() {
+            /* Skip empty lines. */
+        } else if line.starts_with("Weapons") {
+            current = &mut weapons;
+        } else if line.starts_with("Armor") {
+            current = &mut armors;
+        } else if line.starts_with("Rings") {
+            current = &mut rings;
+        } else {
+            current.push(read_equipment(line).map_err(|e| e.at_line(idx + 1))?);
+        }
+    }
+
+    /* Add a no armor and no ring option. */
+    armors.push(Equipment::new("None", 0, 0, 0));
+    rings.push(Equipment::new("None", 0, 0, 0));
+
+    Ok((weapons, armors, rings))
+}
+
+fn read_equipment(line: &str) -> std::result::Result<Equipment, ParseError> {
+    let mut iter = line.split_whitespace();
+
+    Ok(Equipment::new(
+        next_token(&mut iter, line, "name")?,
+        parse_token(line, next_token(&mut iter, line, "cost")?)?,
+        parse_token(line, next_token(&mut iter, line, "damage")?)?,
+        parse_token(line, next_token(&mut iter, line, "armor")?)?,
+    ))
+}
+
+/// Every possible combination of equipment, with its cost.
+pub fn generate_units(hp: usize, weapons: &[Equipment], armors: &[Equipment],
+        rings: &[Equipment]) -> Vec<(usize, Unit)> {
+    let mut units: Vec<(usize, Unit)> = Vec::new();
+
+    for w in weapons {
+        for a in armors {
+            for r1 in rings {
+                for r2 in rings {
+                    if r1 != r2 || r1.name == "None" {
+                        units.push((w.cost + a.cost + r1.cost + r2.cost,
+                            Unit::new(
+                                hp,
+                                w.damage + a.damage + r1.damage + r2.damage,
+                                w.armor + a.armor + r1.armor+ r2.armor
+                            )));
+                    }
+                }
+            }
+        }
+    }
+
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_battle() {
+        let player = Unit::new(8, 5, 5);
+        let boss = read_boss_file("Hit Points: 12\nDamage: 7\nArmor: 2\n").unwrap();
+        assert!(player.simulate_battle(&boss));
+        assert!(!Unit::new(8, 5, 4).simulate_battle(&boss));
+    }
+}
@@ -0,0 +1,809 @@
+//! Day 22: Wizard Simulator 20XX (<https://adventofcode.com/2015/day/22>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use toml::value::Table;
+use toml::Value;
+
+/// Hit points of the player at the start of the battle.
+pub const PLAYER_HP: usize = 50;
+/// Mana of the player at the start of the battle.
+pub const PLAYER_MANA: usize = 500;
+
+/* Write a line to the log of a battle, if it is recorded. */
+macro_rules! log {
+    ($battle:expr, $($arg:tt)*) => {
+        if let Some(log) = &mut $battle.log {
+            writeln!(log, $($arg)*).unwrap();
+        }
+    };
+}
+
+/// Little Henry Case decides that defeating bosses with swords and stuff is boring, and plays a
+/// wizard instead, casting spells which cost mana.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        least_mana(input, Difficulty::Normal, &read_spells(SPELLS)?)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        least_mana(input, Difficulty::Hard, &read_spells(SPELLS)?)
+    }
+}
+
+/// Same puzzle, where the player casts other spells than those of the puzzle, such as the spells
+/// of `day22_spells.toml` in the inputs directory.
+pub struct CustomSpells(pub Vec<Spell>);
+
+impl Solver for CustomSpells {
+    fn part1(&self, input: &str) -> Result<String> {
+        least_mana(input, Difficulty::Normal, &self.0)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        least_mana(input, Difficulty::Hard, &self.0)
+    }
+}
+
+fn least_mana(input: &str, difficulty: Difficulty, spells: &[Spell]) -> Result<String> {
+    let boss = read_boss_file(input)?;
+    let player = Unit::new(PLAYER_HP, PLAYER_MANA, 0);
+
+    let battle = Battle::new(&player, &boss, difficulty, spells);
+    cheapest_win(&battle)
+        .map(|battle| battle.state.value().to_string())
+        .ok_or_else(|| "The player can't win.".into())
+}
+
+/// State of a battle, storing the mana spent so far in the variants.
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    /// The player won.
+    Win(usize),
+    /// The player lost.
+    Loss(usize),
+    /// The battle goes on.
+    Undecided(usize),
+}
+
+impl Outcome {
+    /// Mana spent so far.
+    pub fn value(&self) -> usize {
+        match *self {
+            Outcome::Win(x) => x,
+            Outcome::Loss(x) => x,
+            Outcome::Undecided(x) => x,
+        }
+    }
+
+    /// Win the battle after spending `mana_spent` more mana.
+    pub fn win(&self, mana_spent: usize) -> Outcome {
+        Outcome::Win(self.value().saturating_add(mana_spent))
+    }
+
+    /// Lose the battle after spending `mana_spent` more mana.
+    pub fn loss(&self, mana_spent: usize) -> Outcome {
+        Outcome::Loss(self.value().saturating_add(mana_spent))
+    }
+
+    /// Go on with the battle after spending `mana_spent` more mana.
+    pub fn undecided(&self, mana_spent: usize) -> Outcome {
+        Outcome::Undecided(self.value().saturating_add(mana_spent))
+    }
+}
+
+/// On hard difficulty, the player loses 1 hit point at the start of each of their turns.
+#[derive(Clone, Debug)]
+pub enum Difficulty {
+    /// Rules of the first part.
+    Normal,
+    /// Rules of the second part, where the player loses 1 hit point at the start of their turns.
+    Hard,
+}
+
+/// Player or boss.
+#[derive(Clone, Debug)]
+pub struct Unit {
+    /// Hit points.
+    pub hp: usize,
+    /// Mana left (the boss has none).
+    pub mana: usize,
+    /// Damage dealt by each attack (the player only deals damage with spells).
+    pub damage: usize,
+}
+
+impl Unit {
+    /// Create a unit from its statistics.
+    pub fn new(hp: usize, mana: usize, damage: usize) -> Unit {
+        Unit { hp, mana, damage }
+    }
+}
+
+/// Spells of the puzzle, in the format of `read_spells`, as shipped in `inputs/day22_spells.toml`.
+pub const SPELLS: &str = include_str!("../inputs/day22_spells.toml");
+
+/// Spell which the player can cast, dealing damage and healing at once, and possibly starting an
+/// effect.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Spell {
+    /// Name of the spell, as written in the battle log.
+    pub name: String,
+    /// Mana spent to cast the spell, positive so that the search for the cheapest win ends.
+    pub cost: usize,
+    /// Damage dealt to the boss when the spell is cast.
+    pub damage: usize,
+    /// Hit points given to the player when the spell is cast.
+    pub heal: usize,
+    /// Effect started by the spell, if any.
+    pub effect: Option<Effect>,
+}
+
+/// Effect applied at the start of each turn (the player's and the boss') while it lasts.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Effect {
+    /// Number of turns the effect lasts.
+    pub turns: usize,
+    /// Damage dealt to the boss each turn.
+    pub damage: usize,
+    /// Armor given to the player while the effect is active.
+    pub armor: usize,
+    /// Mana given to the player each turn.
+    pub mana: usize,
+}
+
+/// Battle between the player and the boss. Every spell plays a full round (the player's turn,
+/// then the boss' turn).
+#[derive(Clone, Debug)]
+pub struct Battle<'a> {
+    /// State of the player.
+    pub player: Unit,
+    /// State of the boss.
+    pub boss: Unit,
+    /// Rules of the battle.
+    pub difficulty: Difficulty,
+    /// Whether the battle is over, and the mana spent so far.
+    pub state: Outcome,
+    /// Spells which the player can cast.
+    pub spells: &'a [Spell],
+    /// Number of turns left for the effect of each spell.
+    pub effects: Vec<usize>,
+    /// Indices of the spells cast so far.
+    pub casts: Vec<usize>,
+    /// Description of the turns played so far, in the format of the puzzle text, if recorded.
+    pub log: Option<String>,
+}
+
+impl<'a> Battle<'a> {
+    /// Start a battle, where the player can cast `spells`.
+    pub fn new(player: &Unit, boss: &Unit, difficulty: Difficulty, spells: &'a [Spell]) -> Battle<'a> {
+        Battle {
+            player: player.clone(),
+            boss: boss.clone(),
+            difficulty,
+            state: Outcome::Undecided(0),
+            spells,
+            effects: vec![0; spells.len()],
+            casts: Vec::new(),
+            log: None,
+        }
+    }
+
+    /// Play the spells of index `casts` from this battle, and describe every turn in the format
+    /// of the puzzle text. Returns `None` if one of the spells can't be cast.
+    pub fn replay(&self, casts: &[usize]) -> Option<String> {
+        let mut battle = self.clone();
+        battle.log = Some(String::new());
+
+        for &index in casts {
+            battle.cast(index)?;
+        }
+
+        battle.log
+    }
+
+    /// Names of the spells cast so far.
+    pub fn cast_names(&self) -> Vec<&'a str> {
+        self.casts.iter().map(|&index| self.spells[index].name.as_str()).collect()
+    }
+
+    /// Armor given by the active effects.
+    pub fn armor(&self) -> usize {
+        self.spells.iter().zip(&self.effects)
+            .filter(|&(_, &turns)| turns > 0)
+            .filter_map(|(spell, _)| spell.effect.as_ref())
+            .fold(0, |armor, effect| armor.saturating_add(effect.armor))
+    }
+
+    /* Log the start of a turn, with the state of both units. */
+    fn log_turn(&mut self, turn: &str) {
+        let armor = self.armor();
+
+        if let Some(log) = &mut self.log {
+            if !log.is_empty() {
+                log.push('\n');
+            }
+            writeln!(log, "-- {} turn --", turn).unwrap();
+            writeln!(log, "- Player has {}, {} armor, {} mana",
+                hit_points(self.player.hp), armor, self.player.mana).unwrap();
+            writeln!(log, "- Boss has {}", hit_points(self.boss.hp)).unwrap();
+        }
+    }
+
+    /// Index of the spell called `name`.
+    pub fn spell(&self, name: &str) -> Option<usize> {
+        self.spells.iter().position(|spell| spell.name == name)
+    }
+
+    /// Apply the active effects at the start of a turn, returning the armor of the player.
+    pub fn apply_effects(&mut self) -> usize {
+        let mut armor: usize = 0;
+
+        for (spell, turns) in self.spells.iter().zip(self.effects.iter_mut()) {
+            if let (Some(effect), true) = (&spell.effect, *turns > 0) {
+                let boss_hp = self.boss.hp;
+                self.boss.hp = self.boss.hp.saturating_sub(effect.damage);
+                self.player.mana = self.player.mana.saturating_add(effect.mana);
+                armor = armor.saturating_add(effect.armor);
+                *turns -= 1;
+
+                if let Some(log) = &mut self.log {
+                    let mut actions = Vec::new();
+                    if effect.damage > 0 {
+                        actions.push(format!("deals {} damage", effect.damage));
+                    }
+                    if effect.mana > 0 {
+                        actions.push(format!("provides {} mana", effect.mana));
+                    }
+
+                    if boss_hp > 0 && self.boss.hp == 0 {
+                        writeln!(log, "{} {}. This kills the boss, and the player wins.",
+                            spell.name, actions.join(" and ")).unwrap();
+                        continue;
+                    } else if actions.is_empty() {
+                        writeln!(log, "{}'s timer is now {}.", spell.name, turns).unwrap();
+                    } else {
+                        writeln!(log, "{} {}; its timer is now {}.", spell.name, actions.join(" and "), turns)
+                            .unwrap();
+                    }
+
+                    match (*turns, effect.armor) {
+                        (0, 0) => writeln!(log, "{} wears off.", spell.name).unwrap(),
+                        (0, armor) => writeln!(log, "{} wears off, decreasing armor by {}.",
+                            spell.name, armor).unwrap(),
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        armor
+    }
+
+    /// Play the turn of the boss, who attacks unless the effects kill him first.
+    pub fn boss_turn(&mut self) {
+        self.log_turn("Boss");
+        let armor = self.apply_effects();
+
+        if self.boss.hp == 0 {
+            self.state = self.state.win(0);
+            return;
+        }
+
+        /* The boss always deals at least 1 damage. */
+        let damage = self.boss.damage.saturating_sub(armor).max(1);
+        self.player.hp = self.player.hp.saturating_sub(damage);
+        let kill = if self.player.hp == 0 {
+            self.state = self.state.loss(0);
+            " This kills the player, and the boss wins."
+        } else {
+            ""
+        };
+
+        if armor > 0 {
+            log!(self, "Boss attacks for {} - {} = {} damage!{}", self.boss.damage, armor, damage, kill);
+        } else {
+            log!(self, "Boss attacks for {} damage.{}", damage, kill);
+        }
+    }
+
+    /// Check whether the spell of index `index` can be cast on the next turn: its effect must be
+    /// over once the effects are applied, and the player must have enough mana by then. This is
+    /// also true when the battle ends at the start of the turn, before the spell is cast.
+    pub fn can_cast(&self, index: usize) -> bool {
+        let mut damage: usize = 0;
+        let mut mana = self.player.mana;
+
+        for (spell, &turns) in self.spells.iter().zip(&self.effects) {
+            if let (Some(effect), true) = (&spell.effect, turns > 0) {
+                damage = damage.saturating_add(effect.damage);
+                mana = mana.saturating_add(effect.mana);
+            }
+        }
+
+        let player_dies = matches!(self.difficulty, Difficulty::Hard) && self.player.hp <= 1;
+        player_dies || self.boss.hp <= damage || (self.effects[index] <= 1 && mana >= self.spells[index].cost)
+    }
+
+    /// Cast the spell of index `index`, returning `None` if the spell can't be cast (see
+    /// `can_cast`). The battle is left unchanged in that case.
+    pub fn cast(&mut self, index: usize) -> Option<()> {
+        if !self.can_cast(index) {
+            return None;
+        }
+        let spell = &self.spells[index];
+        self.log_turn("Player");
+
+        if let Difficulty::Hard = self.difficulty {
+            self.player.hp = self.player.hp.saturating_sub(1);
+            if self.player.hp == 0 {
+                log!(self, "Player loses 1 hit point. This kills the player, and the boss wins.");
+                self.state = self.state.loss(0);
+                return Some(());
+            }
+            log!(self, "Player loses 1 hit point.");
+        }
+
+        self.apply_effects();
+        if self.boss.hp == 0 {
+            self.state = self.state.win(0);
+            return Some(());
+        }
+
+        /* The effect of the spell is over, and the player has enough mana, since
+         * it can be cast. */
+        self.player.mana -= spell.cost;
+        self.boss.hp = self.boss.hp.saturating_sub(spell.damage);
+        self.player.hp = self.player.hp.saturating_add(spell.heal);
+        if let Some(effect) = &spell.effect {
+            self.effects[index] = effect.turns;
+        }
+        self.state = self.state.undecided(spell.cost);
+        self.casts.push(index);
+
+        if let Some(log) = &mut self.log {
+            let mut actions = Vec::new();
+            if spell.damage > 0 {
+                actions.push(format!("dealing {} damage", spell.damage));
+            }
+            if spell.heal > 0 {
+                actions.push(format!("healing {}", hit_points(spell.heal)));
+            }
+            match &spell.effect {
+                Some(effect) if effect.armor > 0 =>
+                    actions.push(format!("increasing armor by {}", effect.armor)),
+                _ => (),
+            }
+
+            write!(log, "Player casts {}", spell.name).unwrap();
+            for (idx, action) in actions.iter().enumerate() {
+                let and = if idx > 0 && idx == actions.len() - 1 { "and " } else { "" };
+                write!(log, ", {}{}", and, action).unwrap();
+            }
+            log.push('.');
+            if self.boss.hp == 0 {
+                log.push_str(" This kills the boss, and the player wins.");
+            }
+            log.push('\n');
+        }
+
+        /* The boss may die from the instant damage, before his turn. */
+        if self.boss.hp == 0 {
+            self.state = self.state.win(0);
+            return Some(());
+        }
+
+        self.boss_turn();
+        Some(())
+    }
+}
+
+/// Read a table of spells, such as `SPELLS`. Every spell needs a name and a cost; the damage,
+/// heal and effect default to none, and so do the damage, armor and mana of the effect.
+pub fn read_spells(contents: &str) -> std::result::Result<Vec<Spell>, ParseError> {
+    let document: Value = contents.parse()?;
+    let spells = document.get("spell").and_then(Value::as_array)
+        .ok_or_else(|| ParseError::at_keys(contents, &["spell"], "Expected spells (`[[spell]]`)."))?;
+
+    let mut result: Vec<Spell> = Vec::new();
+    for (idx, spell) in spells.iter().enumerate() {
+        /* The n-th spell starts at the n-th `[[spell]]`, and errors on the whole spell are
+         * reported there. */
+        let invalid = |key: &str, message: &str| {
+            let mut keys = vec!["[[spell]]"; idx + 1];
+            if !key.is_empty() {
+                keys.push(key);
+            }
+            ParseError::at_keys(contents, &keys, message)
+        };
+        let spell = read_spell(spell).map_err(|(key, message)| invalid(key, message))?;
+
+        if result.iter().any(|other| other.name == spell.name) {
+            return Err(invalid("name", "Duplicate spell."));
+        }
+        result.push(spell);
+    }
+
+    Ok(result)
+}
+
+/* Read one spell, returning the offending key and the error message on failure. */
+fn read_spell(spell: &Value) -> std::result::Result<Spell, (&str, &'static str)> {
+    let spell = spell.as_table().ok_or(("", "Expected a table."))?;
+    check_keys(spell, &["name", "cost", "damage", "heal", "effect"])?;
+
+    let name = spell.get("name").and_then(Value::as_str)
+        .ok_or(("name", "Expected the name of the spell."))?;
+    if !spell.contains_key("cost") {
+        return Err(("", "Missing cost."));
+    }
+    /* A free spell could be cast forever, so the search would never end. */
+    let cost = read_number(spell, "cost")?;
+    if cost == 0 {
+        return Err(("cost", "Expected a positive cost."));
+    }
+
+    let effect = match spell.get("effect") {
+        None => None,
+        Some(Value::Table(effect)) => {
+            check_keys(effect, &["turns", "damage", "armor", "mana"])?;
+            let turns = read_number(effect, "turns")?;
+            if turns == 0 {
+                return Err(("effect", "Expected a positive number of turns."));
+            }
+
+            Some(Effect {
+                turns,
+                damage: read_number(effect, "damage")?,
+                armor: read_number(effect, "armor")?,
+                mana: read_number(effect, "mana")?,
+            })
+        },
+        Some(_) => return Err(("effect", "Expected a table.")),
+    };
+
+    Ok(Spell {
+        name: name.to_string(),
+        cost,
+        damage: read_number(spell, "damage")?,
+        heal: read_number(spell, "heal")?,
+        effect,
+    })
+}
+
+fn check_keys<'a>(table: &'a Table, keys: &[&str]) -> std::result::Result<(), (&'a str, &'static str)> {
+    match table.keys().find(|key| !keys.contains(&key.as_str())) {
+        Some(key) => Err((key, "Unknown key.")),
+        None => Ok(()),
+    }
+}
+
+/* Read a number of a table, which defaults to 0. */
+fn read_number<'a>(table: &Table, key: &'a str) -> std::result::Result<usize, (&'a str, &'static str)> {
+    match table.get(key) {
+        None => Ok(0),
+        Some(Value::Integer(x)) if *x >= 0 => Ok(*x as usize),
+        Some(_) => Err((key, "Expected a non-negative integer.")),
+    }
+}
+
+/// Read the boss statistics (hit points and damage).
+pub fn read_boss_file(contents: &str) -> std::result::Result<Unit, ParseError> {
+    let mut iter = contents.lines();
+
+    Ok(Unit::new(
+        read_statistic(iter.next(), 1, "Hit Points")?,
+        0,
+        read_statistic(iter.next(), 2, "Damage")?,
+    ))
+}
+
+/* Read a line of the boss statistics (e.g. `Hit Points: 55`). */
+fn read_statistic(line: Option<&str>, line_number: usize, name: &str)
+        -> std::result::Result<usize, ParseError> {
+    let line = line.ok_or_else(||
+        ParseError::missing("", &format!("Missing {}.", name)).at_line(line_number))?;
+    let mut iter = line.split(": ");
+
+    match next_token(&mut iter, line, name) {
+        Ok(s) if s == name => (),
+        Ok(s) => return Err(ParseError::new(line, s, &format!("Expected {}.", name)).at_line(line_number)),
+        Err(e) => return Err(e.at_line(line_number)),
+    }
+
+    next_token(&mut iter, line, name)
+        .and_then(|token| parse_token(line, token))
+        .map_err(|e| e.at_line(line_number))
+}
+
+/// Statistics of the search for the cheapest win.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SearchStats {
+    /// Battles whose next spells were tried.
+    pub expanded: usize,
+    /// Battles dropped without trying their next spells: lost, or already reached with less mana.
+    pub pruned: usize,
+}
+
+impl fmt::Display for SearchStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} battles expanded, {} pruned.", self.expanded, self.pruned)
+    }
+}
+
+/* Battle waiting in the search, ordered by the least mana it can be won with,
+ * the cheapest first. */
+struct Node<'a> {
+    bound: usize,
+    battle: Battle<'a>,
+}
+
+impl PartialEq for Node<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bound == other.bound
+    }
+}
+
+impl Eq for Node<'_> {}
+
+impl PartialOrd for Node<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.bound.cmp(&self.bound)
+    }
+}
+
+/// Way to win the battle spending the least mana, if the player can win: the state of the battle
+/// gives the mana spent, and `casts` the spells.
+pub fn cheapest_win<'a>(battle: &Battle<'a>) -> Option<Battle<'a>> {
+    search(battle).0
+}
+
+/// Find the cheapest win with an A* search over the mana spent, along with statistics of the
+/// search. Battles are only expanded the first time their state (hit points, mana and effects)
+/// is reached, since later ones spent at least as much mana.
+pub fn search<'a>(battle: &Battle<'a>) -> (Option<Battle<'a>>, SearchStats) {
+    let mut stats = SearchStats::default();
+    let mut visited = HashSet::new();
+    let mut to_do = BinaryHeap::new();
+
+    /* No spell deals more damage per mana than the most efficient one, so the
+     * damage left to deal, beyond the damage of the active effects, costs at
+     * least that much mana: the bound never overestimates. The values of the
+     * spells may be anything, so the products are computed on 128 bits. */
+    let effect_damage = |effect: &Effect, turns: usize| effect.damage as u128 * turns as u128;
+    let efficiency = battle.spells.iter()
+        .map(|spell| (spell.cost as u128, spell.damage as u128
+            + spell.effect.as_ref().map_or(0, |effect| effect_damage(effect, effect.turns))))
+        .filter(|&(_, damage)| damage > 0)
+        .min_by(|(cost1, damage1), (cost2, damage2)| {
+            cost1.saturating_mul(*damage2).cmp(&cost2.saturating_mul(*damage1))
+        });
+    let bound = |battle: &Battle| {
+        let pending: u128 = battle.spells.iter().zip(&battle.effects)
+            .filter_map(|(spell, &turns)| spell.effect.as_ref().map(|effect| effect_damage(effect, turns)))
+            .sum();
+        let mana = match efficiency {
+            Some((cost, damage)) => {
+                (battle.boss.hp as u128).saturating_sub(pending).saturating_mul(cost) / damage
+            },
+            None => 0,
+        };
+        usize::try_from(mana).unwrap_or(usize::MAX).saturating_add(battle.state.value())
+    };
+
+    to_do.push(Node { bound: bound(battle), battle: battle.clone() });
+    while let Some(Node { battle: current, .. }) = to_do.pop() {
+        if let Outcome::Win(_) = current.state {
+            return (Some(current), stats);
+        }
+
+        let key = (current.player.hp, current.player.mana, current.boss.hp, current.effects.clone());
+        if !visited.insert(key) {
+            stats.pruned += 1;
+            continue;
+        }
+        stats.expanded += 1;
+
+        for spell in 0..current.spells.len() {
+            let mut next = current.clone();
+            if next.cast(spell).is_none() {
+                continue;
+            }
+
+            if let Outcome::Loss(_) = next.state {
+                stats.pruned += 1;
+            } else {
+                to_do.push(Node { bound: bound(&next), battle: next });
+            }
+        }
+    }
+
+    (None, stats)
+}
+
+/* Number of hit points, as a phrase of the battle log. */
+fn hit_points(hp: usize) -> String {
+    match hp {
+        1 => "1 hit point".to_string(),
+        _ => format!("{} hit points", hp),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cast(battle: &mut Battle, name: &str) {
+        let spell = battle.spell(name).unwrap();
+        battle.cast(spell).unwrap();
+    }
+
+    #[test]
+    fn first_example() {
+        let spells = read_spells(SPELLS).unwrap();
+        let boss = read_boss_file("Hit Points: 13\nDamage: 8\n").unwrap();
+        let mut battle = Battle::new(&Unit::new(10, 250, 0), &boss, Difficulty::Normal, &spells);
+
+        cast(&mut battle, "Poison");
+        assert_eq!((battle.player.hp, battle.boss.hp), (2, 10));
+        cast(&mut battle, "Magic Missile");
+        /* The boss dies from poison at the start of his turn. */
+        assert_eq!(battle.boss.hp, 0);
+        assert!(matches!(battle.state, Outcome::Win(226)));
+    }
+
+    #[test]
+    fn second_example() {
+        let spells = read_spells(SPELLS).unwrap();
+        let boss = read_boss_file("Hit Points: 14\nDamage: 8\n").unwrap();
+        let mut battle = Battle::new(&Unit::new(10, 250, 0), &boss, Difficulty::Normal, &spells);
+
+        for name in &["Recharge", "Shield", "Drain", "Poison", "Magic Missile"] {
+            cast(&mut battle, name);
+        }
+        assert_eq!(battle.player.hp, 1);
+        assert!(matches!(battle.state, Outcome::Win(641)));
+    }
+
+    #[test]
+    fn replay() {
+        let spells = read_spells(SPELLS).unwrap();
+        let boss = read_boss_file("Hit Points: 13\nDamage: 8\n").unwrap();
+        let battle = Battle::new(&Unit::new(10, 250, 0), &boss, Difficulty::Normal, &spells);
+
+        let win = cheapest_win(&battle).unwrap();
+        assert_eq!(win.cast_names(), vec!["Poison", "Magic Missile"]);
+        assert_eq!(battle.replay(&win.casts).unwrap(), "\
+-- Player turn --
+- Player has 10 hit points, 0 armor, 250 mana
+- Boss has 13 hit points
+Player casts Poison.
+
+-- Boss turn --
+- Player has 10 hit points, 0 armor, 77 mana
+- Boss has 13 hit points
+Poison deals 3 damage; its timer is now 5.
+Boss attacks for 8 damage.
+
+-- Player turn --
+- Player has 2 hit points, 0 armor, 77 mana
+- Boss has 10 hit points
+Poison deals 3 damage; its timer is now 4.
+Player casts Magic Missile, dealing 4 damage.
+
+-- Boss turn --
+- Player has 2 hit points, 0 armor, 24 mana
+- Boss has 3 hit points
+Poison deals 3 damage. This kills the boss, and the player wins.
+");
+
+        let boss = read_boss_file("Hit Points: 14\nDamage: 8\n").unwrap();
+        let battle = Battle::new(&Unit::new(10, 250, 0), &boss, Difficulty::Normal, &spells);
+        let casts: Vec<usize> = ["Recharge", "Shield", "Drain", "Poison", "Magic Missile"].iter()
+            .map(|name| battle.spell(name).unwrap())
+            .collect();
+        let log = battle.replay(&casts).unwrap();
+        for line in &[
+            "Player casts Drain, dealing 2 damage, and healing 2 hit points.",
+            "Player casts Shield, increasing armor by 7.",
+            "- Player has 1 hit point, 7 armor, 211 mana",
+            "Recharge provides 101 mana; its timer is now 0.\nRecharge wears off.",
+            "Boss attacks for 8 - 7 = 1 damage!",
+            "Shield's timer is now 0.\nShield wears off, decreasing armor by 7.",
+        ] {
+            assert!(log.contains(line), "{}", line);
+        }
+
+        /* Shield can't be cast twice in a row, and the battle is left as it was. */
+        assert_eq!(battle.replay(&[casts[1], casts[1]]), None);
+        let mut shielded = Battle::new(&Unit::new(10, 500, 0), &boss, Difficulty::Normal, &spells);
+        shielded.cast(casts[1]).unwrap();
+        let (hp, mana, effects) = (shielded.player.hp, shielded.player.mana, shielded.effects.clone());
+        assert_eq!(shielded.cast(casts[1]), None);
+        assert_eq!((shielded.player.hp, shielded.player.mana, &shielded.effects), (hp, mana, &effects));
+        /* Shield can be cast again on the turn it ends. */
+        for &index in &[casts[4], casts[4]] {
+            shielded.cast(index).unwrap();
+        }
+        assert!(shielded.can_cast(casts[1]));
+    }
+
+    /* Least mana to win, trying every sequence of spells which costs less
+     * than the best win found so far. */
+    fn exhaustive(battle: &Battle, best: &mut Option<usize>) {
+        for spell in 0..battle.spells.len() {
+            let mut next = battle.clone();
+            if next.cast(spell).is_none() || best.is_some_and(|best| next.state.value() >= best) {
+                continue;
+            }
+            match next.state {
+                Outcome::Win(x) => *best = Some(x),
+                Outcome::Loss(_) => (),
+                Outcome::Undecided(_) => exhaustive(&next, best),
+            }
+        }
+    }
+
+    #[test]
+    fn best_first_search() {
+        let spells = read_spells(SPELLS).unwrap();
+        let player = Unit::new(20, 250, 0);
+
+        for (hp, damage, difficulty) in [(13, 8, Difficulty::Normal), (30, 5, Difficulty::Normal),
+            (30, 5, Difficulty::Hard), (35, 6, Difficulty::Normal), (15, 9, Difficulty::Hard)] {
+            let battle = Battle::new(&player, &Unit::new(hp, 0, damage), difficulty, &spells);
+            let (win, stats) = search(&battle);
+            let mut best = None;
+            exhaustive(&battle, &mut best);
+            assert_eq!(win.map(|win| win.state.value()), best, "{} {}", hp, damage);
+            assert!(stats.expanded > 0);
+        }
+
+        /* A boss which can't be beaten with the mana of the player. */
+        let battle = Battle::new(&player, &Unit::new(200, 0, 10), Difficulty::Normal, &spells);
+        let (win, stats) = search(&battle);
+        assert!(win.is_none());
+        assert!(stats.pruned > 0);
+
+        /* Spells with huge values don't overflow the bound or the battle. */
+        let max = i64::MAX;
+        let spells = read_spells(&format!("[[spell]]\nname = \"Nuke\"\ncost = {0}\ndamage = {0}\n\
+            effect = {{ turns = {0}, damage = {0}, armor = {0}, mana = {0} }}\n\n\
+            [[spell]]\nname = \"Zap\"\ncost = 1\ndamage = 1\nheal = {0}\n", max)).unwrap();
+        let player = Unit::new(usize::MAX, usize::MAX, 0);
+        let battle = Battle::new(&player, &Unit::new(3, 0, 1), Difficulty::Normal, &spells);
+        let win = cheapest_win(&battle).unwrap();
+        assert_eq!(win.state.value(), 3);
+    }
+
+    #[test]
+    fn spells() {
+        let spells = read_spells(SPELLS).unwrap();
+        assert_eq!(spells.iter().map(|spell| spell.cost).collect::<Vec<_>>(), vec![53, 73, 113, 173, 229]);
+        assert_eq!(spells[2].effect, Some(Effect { turns: 6, armor: 7, ..Effect::default() }));
+
+        /* A custom spell which gives mana over time. */
+        let spells = read_spells("[[spell]]\nname = \"Zap\"\ncost = 10\ndamage = 5\n\n\
+            [[spell]]\nname = \"Regenerate\"\ncost = 50\neffect = { turns = 2, mana = 1 }\n").unwrap();
+        assert_eq!(spells[1].effect.as_ref().map(|effect| effect.turns), Some(2));
+
+        let error = read_spells("[[spell]]\nname = \"Zap\"\ncost = 10\n\n\
+            [[spell]]\nname = \"Zap\"\ncost = -1\n").unwrap_err();
+        assert_eq!((error.line, error.token.as_str()), (7, "cost"));
+        let error = read_spells("[[spell]]\nname = \"Rest\"\ncost = 0\nheal = 20\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (3, "Expected a positive cost."));
+        let error = read_spells("[[spell]]\nname = \"Zap\"\ncost = 10\nrange = 3\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (4, "Unknown key."));
+    }
+}
@@ -0,0 +1,1344 @@
+//! Day 23: Opening the Turing Lock (<https://adventofcode.com/2015/day/23>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Write};
+
+/// Little Jane Marie just got her very first computer, which supports two registers and six
+/// instructions.
+pub struct Puzzle;
+
+/* The program is expected to halt in a few hundred steps. */
+const LIMITS: Limits = Limits { max_steps: Some(1_000_000), detect_loops: true };
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let mut computer = Computer::new();
+        computer.load_program(input)?;
+        execute(&mut computer)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let mut computer = Computer::new();
+        computer.load_program(input)?;
+        computer.set_register(0, 1);
+        execute(&mut computer)
+    }
+}
+
+/* Run the program, skipping over the loops it is known to compute, and read
+ * register `b` if it exited. */
+fn execute(computer: &mut Computer) -> Result<String> {
+    let idioms = ControlFlowGraph::new(&computer.memory).idioms();
+
+    match computer.run_with_idioms(&LIMITS, &idioms) {
+        Halt::Finished | Halt::JumpedOutOfBounds { .. } => Ok(computer.registers[1].to_string()),
+        halt => Err(halt.into()),
+    }
+}
+
+/// Name of a register, by index (`a` is 0, `b` is 1, and so on).
+pub fn register_name(register: usize) -> char {
+    (b'a' + register as u8) as char
+}
+
+/// Index of a register given its name, a single lowercase letter.
+pub fn register(name: &str) -> Option<usize> {
+    match name.as_bytes() {
+        [c @ b'a'..=b'z'] => Some((c - b'a') as usize),
+        _ => None,
+    }
+}
+
+/// Relative offset of a jump.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JumpOffset {
+    /// Jump forwards by this number of instructions.
+    Positive(usize),
+    /// Jump backwards by this number of instructions.
+    Negative(usize),
+}
+
+impl JumpOffset {
+    /// Read an offset (e.g. `+4` or `-7`), which must fit in an `i64` like the registers.
+    pub fn new(s: &str) -> std::result::Result<JumpOffset, ParseError> {
+        let (sign, size) = s.split_at(s.find(|c: char| c.is_ascii_digit()).unwrap_or(0));
+        let size = match sign {
+            "+" | "-" => parse_token::<usize>(s, size)?,
+            _ => return Err(ParseError::new(s, s, "Unreadable jump offset.")),
+        };
+        if i64::try_from(size).is_err() {
+            return Err(ParseError::new(s, s, "Jump offset out of range."));
+        }
+
+        match sign {
+            "+" => Ok(JumpOffset::Positive(size)),
+            _ => Ok(JumpOffset::Negative(size)),
+        }
+    }
+
+    /// Index reached by jumping from an instruction, unless it is before the start of the memory.
+    pub fn target(&self, from: usize) -> Option<usize> {
+        match self {
+            JumpOffset::Positive(x) => from.checked_add(*x),
+            JumpOffset::Negative(x) => from.checked_sub(*x),
+        }
+    }
+
+    /// Signed value of the offset. Offsets which don't fit in an `i64` saturate, so that the
+    /// jump still goes in the same direction (and out of the program).
+    pub fn value(&self) -> i64 {
+        match self {
+            JumpOffset::Positive(x) => i64::try_from(*x).unwrap_or(i64::MAX),
+            JumpOffset::Negative(x) => i64::try_from(*x).map_or(i64::MIN, |x| -x),
+        }
+    }
+}
+
+impl From<i64> for JumpOffset {
+    fn from(value: i64) -> JumpOffset {
+        if value >= 0 {
+            JumpOffset::Positive(value as usize)
+        } else {
+            JumpOffset::Negative(value.unsigned_abs() as usize)
+        }
+    }
+}
+
+impl fmt::Display for JumpOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JumpOffset::Positive(x) => write!(f, "+{}", x),
+            JumpOffset::Negative(x) => write!(f, "-{}", x),
+        }
+    }
+}
+
+/// Argument of an instruction: the value of a register, or a constant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operand {
+    /// Value of a register, by index.
+    Register(usize),
+    /// Constant value.
+    Value(i64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(r) => write!(f, "{}", register_name(*r)),
+            Operand::Value(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+/// Instructions, where registers are referred to by their index (`a` is 0, `b` is 1). `cpy x r`,
+/// `add x r` and `mul x r` store their result in register `r`, `jnz x y` jumps by `y` if `x`
+/// isn't 0, and `out x` sends `x` to the output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    /// `hlf r`: halve register `r`.
+    Half(usize),
+    /// `tpl r`: triple register `r`.
+    Triple(usize),
+    /// `inc r`: increment register `r`.
+    Increment(usize),
+    /// `jmp offset`: jump.
+    Jump(JumpOffset),
+    /// `jie r, offset`: jump if register `r` is even.
+    JumpIfEven(usize, JumpOffset),
+    /// `jio r, offset`: jump if register `r` is 1.
+    JumpIfOne(usize, JumpOffset),
+    /// `cpy x r`: copy `x` to register `r`.
+    Copy(Operand, usize),
+    /// `dec r`: decrement register `r`.
+    Decrement(usize),
+    /// `jnz x y`: jump by `y` if `x` isn't 0.
+    JumpIfNotZero(Operand, Operand),
+    /// `add x r`: add `x` to register `r`.
+    Add(Operand, usize),
+    /// `mul x r`: multiply register `r` by `x`.
+    Multiply(Operand, usize),
+    /// `out x`: send `x` to the output.
+    Out(Operand),
+}
+
+impl Instruction {
+    /// Offset of the jump, if the instruction is one and the offset is a constant.
+    pub fn offset(&self) -> Option<JumpOffset> {
+        match self {
+            Instruction::Jump(x) | Instruction::JumpIfEven(_, x) | Instruction::JumpIfOne(_, x) => Some(*x),
+            Instruction::JumpIfNotZero(_, Operand::Value(x)) => Some(JumpOffset::from(*x)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Half(r) => write!(f, "hlf {}", register_name(*r)),
+            Instruction::Triple(r) => write!(f, "tpl {}", register_name(*r)),
+            Instruction::Increment(r) => write!(f, "inc {}", register_name(*r)),
+            Instruction::Jump(x) => write!(f, "jmp {}", x),
+            Instruction::JumpIfEven(r, x) => write!(f, "jie {}, {}", register_name(*r), x),
+            Instruction::JumpIfOne(r, x) => write!(f, "jio {}, {}", register_name(*r), x),
+            Instruction::Copy(x, r) => write!(f, "cpy {} {}", x, register_name(*r)),
+            Instruction::Decrement(r) => write!(f, "dec {}", register_name(*r)),
+            Instruction::JumpIfNotZero(x, y) => write!(f, "jnz {} {}", x, y),
+            Instruction::Add(x, r) => write!(f, "add {} {}", x, register_name(*r)),
+            Instruction::Multiply(x, r) => write!(f, "mul {} {}", x, register_name(*r)),
+            Instruction::Out(x) => write!(f, "out {}", x),
+        }
+    }
+}
+
+/// Every opcode the computer can execute.
+pub const OPCODES: [&str; 12] = [
+    "hlf", "tpl", "inc", "jmp", "jie", "jio",
+    "cpy", "dec", "jnz", "add", "mul", "out",
+];
+
+/// Dialect of assembly run by a computer: its number of registers (named `a`, `b`, and so on) and
+/// the opcodes it understands.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstructionSet {
+    /// Number of registers.
+    pub registers: usize,
+    /// Opcodes understood, out of `OPCODES`.
+    pub opcodes: Vec<&'static str>,
+}
+
+impl InstructionSet {
+    /// Jane Marie's computer: registers `a` and `b`, and `hlf`, `tpl`, `inc`, `jmp`, `jie` and
+    /// `jio`.
+    pub fn day23() -> InstructionSet {
+        InstructionSet { registers: 2, opcodes: OPCODES[..6].to_vec() }
+    }
+
+    /// Assembunny (from Advent of Code 2016): registers `a` to `d`, `cpy`, `inc`, `dec`, `jnz` and
+    /// `out`, with `add` and `mul` to speed up its loops.
+    pub fn assembunny() -> InstructionSet {
+        InstructionSet { registers: 4, opcodes: vec!["cpy", "inc", "dec", "jnz", "add", "mul", "out"] }
+    }
+
+    /// Every opcode, with the given number of registers (at most 26).
+    pub fn full(registers: usize) -> InstructionSet {
+        InstructionSet { registers: registers.min(26), opcodes: OPCODES.to_vec() }
+    }
+
+    /// Read an instruction (e.g. `jie a, +4`).
+    pub fn read_instruction(&self, instruction: &str) -> std::result::Result<Instruction, ParseError> {
+        read_instruction(self, instruction, JumpOffset::new)
+    }
+
+    /// Assemble a program, one instruction per line. A line can start with a label (e.g. `loop:`),
+    /// which jumps can target instead of giving an offset (e.g. `jie a, loop`). Empty lines and
+    /// comments (from `;` to the end of the line) are ignored.
+    pub fn assemble(&self, source: &str) -> std::result::Result<Vec<Instruction>, ParseError> {
+        /* Find the index of every label first, as jumps can go forward. */
+        let mut labels = HashMap::new();
+        let mut instructions = Vec::new();
+
+        for (idx, line) in source.lines().enumerate() {
+            let code = line.split(';').next().unwrap_or("").trim();
+            let (label, instruction) = match code.split_whitespace().next() {
+                Some(token) if token.ends_with(':') =>
+                    (Some(&token[..token.len() - 1]), code[token.len()..].trim()),
+                _ => (None, code),
+            };
+
+            if let Some(label) = label {
+                if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    return Err(ParseError::new(line, label, "Invalid label.").at_line(idx + 1));
+                }
+                if labels.insert(label, instructions.len()).is_some() {
+                    return Err(ParseError::new(line, label, "Duplicate label.").at_line(idx + 1));
+                }
+            }
+            if !instruction.is_empty() {
+                instructions.push((idx, line, instruction));
+            }
+        }
+
+        instructions.iter().enumerate()
+            .map(|(address, &(idx, line, instruction))| {
+                let offset = |token: &str| match labels.get(token) {
+                    Some(&target) if target >= address => Ok(JumpOffset::Positive(target - address)),
+                    Some(&target) => Ok(JumpOffset::Negative(address - target)),
+                    None if token.starts_with(['+', '-']) => JumpOffset::new(token),
+                    None => Err(ParseError::new(token, token, "Unknown label.")),
+                };
+                read_instruction(self, instruction, offset)
+                    .map_err(|e| e.within(line, instruction).at_line(idx + 1))
+            })
+            .collect()
+    }
+}
+
+/* Read an instruction of the set, where the jump targets are read by `offset`. */
+fn read_instruction<F>(set: &InstructionSet, instruction: &str, offset: F)
+    -> std::result::Result<Instruction, ParseError>
+    where F: Fn(&str) -> std::result::Result<JumpOffset, ParseError> {
+
+    let mut iter = instruction.split_whitespace();
+
+    let register = |token: &str| -> std::result::Result<usize, ParseError> {
+        /* Watch out for the comma after the register name. */
+        let name = token.trim_end_matches(',');
+        register(name)
+            .filter(|&r| r < set.registers)
+            .ok_or_else(|| ParseError::new(instruction, name, "Invalid register."))
+    };
+
+    let offset = |token: &str| offset(token).map_err(|e| e.within(instruction, token));
+
+    /* Operands are constants or registers. The offset of `jnz` can also be
+     * given as a label. */
+    let operand = |token: &str, jump: bool| -> std::result::Result<Operand, ParseError> {
+        let token = token.trim_end_matches(',');
+        match token.parse() {
+            Ok(x) => Ok(Operand::Value(x)),
+            Err(_) if register(token).is_ok() => Ok(Operand::Register(register(token)?)),
+            Err(_) if jump => Ok(Operand::Value(offset(token)?.value())),
+            Err(_) => Err(ParseError::new(instruction, token, "Invalid register or value.")),
+        }
+    };
+
+    let opcode = next_token(&mut iter, instruction, "instruction")?;
+    if !set.opcodes.contains(&opcode) {
+        return Err(ParseError::new(instruction, opcode, "Invalid instruction."));
+    }
+
+    let result = match opcode {
+        "hlf" | "tpl" | "inc" | "dec" => {
+            let r = register(next_token(&mut iter, instruction, "register")?)?;
+            match opcode {
+                "hlf" => Instruction::Half(r),
+                "tpl" => Instruction::Triple(r),
+                "inc" => Instruction::Increment(r),
+                _ => Instruction::Decrement(r),
+            }
+        },
+        "jmp" => {
+            Instruction::Jump(offset(next_token(&mut iter, instruction, "offset")?)?)
+        },
+        "jie" | "jio" => {
+            let r = register(next_token(&mut iter, instruction, "register")?)?;
+            let x = offset(next_token(&mut iter, instruction, "offset")?)?;
+            match opcode {
+                "jie" => Instruction::JumpIfEven(r, x),
+                _ => Instruction::JumpIfOne(r, x),
+            }
+        },
+        "cpy" | "add" | "mul" => {
+            let x = operand(next_token(&mut iter, instruction, "operand")?, false)?;
+            let r = register(next_token(&mut iter, instruction, "register")?)?;
+            match opcode {
+                "cpy" => Instruction::Copy(x, r),
+                "add" => Instruction::Add(x, r),
+                _ => Instruction::Multiply(x, r),
+            }
+        },
+        "jnz" => {
+            let x = operand(next_token(&mut iter, instruction, "operand")?, false)?;
+            let y = operand(next_token(&mut iter, instruction, "offset")?, true)?;
+            Instruction::JumpIfNotZero(x, y)
+        },
+        "out" => {
+            Instruction::Out(operand(next_token(&mut iter, instruction, "operand")?, false)?)
+        },
+        s => return Err(ParseError::new(instruction, s, "Invalid instruction.")),
+    };
+
+    Ok(result)
+}
+
+/// Assemble a program for the computer of day 23 (see `InstructionSet::assemble`).
+pub fn assemble(source: &str) -> std::result::Result<Vec<Instruction>, ParseError> {
+    InstructionSet::day23().assemble(source)
+}
+
+/// Print a program, where the jumps target labels named after the index of their target (e.g.
+/// `L18`), instead of giving offsets. Jumps out of the program keep their offset.
+pub fn disassemble(memory: &[Instruction]) -> String {
+    let target = |address: usize, instruction: &Instruction| {
+        instruction.offset()
+            .and_then(|offset| offset.target(address))
+            .filter(|&target| target <= memory.len())
+    };
+    let targets: BTreeSet<usize> = memory.iter().enumerate()
+        .filter_map(|(address, instruction)| target(address, instruction))
+        .collect();
+
+    let mut output = String::new();
+
+    for (address, instruction) in memory.iter().enumerate() {
+        let label = if targets.contains(&address) { format!("L{}:", address) } else { String::new() };
+        let code = match (instruction, target(address, instruction)) {
+            (Instruction::Jump(_), Some(t)) => format!("jmp L{}", t),
+            (Instruction::JumpIfEven(r, _), Some(t)) => format!("jie {}, L{}", register_name(*r), t),
+            (Instruction::JumpIfOne(r, _), Some(t)) => format!("jio {}, L{}", register_name(*r), t),
+            (Instruction::JumpIfNotZero(x, _), Some(t)) => format!("jnz {} L{}", x, t),
+            _ => instruction.to_string(),
+        };
+        output.push_str(&format!("{:<8}{}\n", label, code));
+    }
+
+    /* Jumps right after the end of the program finish it. */
+    if targets.contains(&memory.len()) {
+        output.push_str(&format!("L{}:\n", memory.len()));
+    }
+
+    output
+}
+
+/// Why a program stopped running.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Halt {
+    /// The instruction pointer reached the end of the program.
+    Finished,
+    /// A jump led before the start of the program, or after its end. The instruction pointer is
+    /// left on the jump.
+    JumpedOutOfBounds {
+        /// Index of the jump.
+        from: usize,
+        /// Index the jump led to (saturating at the bounds of an `i64`).
+        target: i64,
+    },
+    /// The program didn't halt within the given number of steps.
+    StepLimit(usize),
+    /// The state of the computer after this number of steps was seen before, so the program runs
+    /// forever.
+    LoopDetected(usize),
+}
+
+impl fmt::Display for Halt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Halt::Finished => write!(f, "The program finished."),
+            Halt::JumpedOutOfBounds { from, target } =>
+                write!(f, "The jump at {} leads out of the program, to {}.", from, target),
+            Halt::StepLimit(steps) => write!(f, "The program didn't halt within {} steps.", steps),
+            Halt::LoopDetected(steps) =>
+                write!(f, "The program runs forever (its state after {} steps was seen before).", steps),
+        }
+    }
+}
+
+impl Error for Halt {}
+
+/// Limits on running a program, which may never halt.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Limits {
+    /// Maximum number of instructions executed.
+    pub max_steps: Option<usize>,
+    /// Remember the states of the computer (instruction pointer and registers), to stop as soon as
+    /// one repeats. This costs memory for each step, and ignores the output.
+    pub detect_loops: bool,
+}
+
+/// Computer with its registers, the program loaded in memory and what the program sent to the
+/// output, which runs the assembly of an instruction set.
+#[derive(Debug)]
+pub struct Computer {
+    /// Value of each register, by index.
+    pub registers: Vec<i64>,
+    /// Program being run.
+    pub memory: Vec<Instruction>,
+    /// Index of the next instruction.
+    pub instruction_pointer: usize,
+    /// Values sent by `out` instructions.
+    pub output: Vec<i64>,
+    /// Instructions understood when loading a program.
+    pub instruction_set: InstructionSet,
+}
+
+impl Default for Computer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Computer {
+    /// Computer of day 23, with two registers.
+    pub fn new() -> Computer {
+        Computer::with_instruction_set(InstructionSet::day23())
+    }
+
+    /// Create a computer for the given dialect, with every register at 0 and no program.
+    pub fn with_instruction_set(instruction_set: InstructionSet) -> Computer {
+        Computer {
+            registers: vec![0; instruction_set.registers],
+            memory: Vec::new(),
+            instruction_pointer: 0,
+            output: Vec::new(),
+            instruction_set,
+        }
+    }
+
+    /// Set the value of a register, by index.
+    pub fn set_register(&mut self, register: usize, value: i64) {
+        self.registers[register] = value;
+    }
+
+    /// Load a program, one instruction per line, at the end of the memory. Jumps can use labels
+    /// (see `assemble`).
+    pub fn load_program(&mut self, program: &str) -> std::result::Result<(), ParseError> {
+        self.memory.extend(self.instruction_set.assemble(program)?);
+        Ok(())
+    }
+
+    /// Read an instruction of day 23 (e.g. `jie a, +4`).
+    pub fn read_instruction(instruction: &str) -> std::result::Result<Instruction, ParseError> {
+        InstructionSet::day23().read_instruction(instruction)
+    }
+
+    /// Run the program until it halts, without any limit.
+    pub fn execute_program(&mut self) -> Halt {
+        self.run(&Limits::default())
+    }
+
+    /// Run the program until it halts, or until it reaches one of the limits.
+    pub fn run(&mut self, limits: &Limits) -> Halt {
+        self.run_with_idioms(limits, &[])
+    }
+
+    /// Run the program like `run`, but execute the idioms at once when reaching them. Each idiom
+    /// counts as one step.
+    pub fn run_with_idioms(&mut self, limits: &Limits, idioms: &[Idiom]) -> Halt {
+        let mut states = HashSet::new();
+        let mut steps = 0;
+
+        loop {
+            if self.instruction_pointer >= self.memory.len() {
+                return Halt::Finished;
+            }
+            if limits.max_steps.is_some_and(|max| steps >= max) {
+                return Halt::StepLimit(steps);
+            }
+            if limits.detect_loops && !states.insert((self.instruction_pointer, self.registers.clone())) {
+                return Halt::LoopDetected(steps);
+            }
+            if !idioms.iter().any(|idiom| idiom.fast_forward(self).is_some()) {
+                if let Err(halt) = self.step() {
+                    return halt;
+                }
+            }
+            steps += 1;
+        }
+    }
+
+    /// Execute the instruction at the instruction pointer. Fails with `Finished` if the
+    /// instruction pointer is past the program, or `JumpedOutOfBounds` if the instruction is a
+    /// jump out of the program.
+    pub fn step(&mut self) -> std::result::Result<(), Halt> {
+        match *self.memory.get(self.instruction_pointer).ok_or(Halt::Finished)? {
+            Instruction::Half(x) => self.registers[x] /= 2,
+            Instruction::Triple(x) => self.registers[x] = self.registers[x].wrapping_mul(3),
+            Instruction::Increment(x) => self.registers[x] = self.registers[x].wrapping_add(1),
+            Instruction::Decrement(x) => self.registers[x] = self.registers[x].wrapping_sub(1),
+            Instruction::Copy(x, r) => self.registers[r] = self.value(x),
+            Instruction::Add(x, r) => self.registers[r] = self.registers[r].wrapping_add(self.value(x)),
+            Instruction::Multiply(x, r) => self.registers[r] = self.registers[r].wrapping_mul(self.value(x)),
+            Instruction::Out(x) => self.output.push(self.value(x)),
+            Instruction::Jump(x) => return self.jump(x.value()),
+            Instruction::JumpIfEven(r, x) if self.registers[r] % 2 == 0 => return self.jump(x.value()),
+            Instruction::JumpIfOne(r, x) if self.registers[r] == 1 => return self.jump(x.value()),
+            Instruction::JumpIfNotZero(x, y) if self.value(x) != 0 => return self.jump(self.value(y)),
+            Instruction::JumpIfEven(..) | Instruction::JumpIfOne(..) | Instruction::JumpIfNotZero(..) => (),
+        }
+
+        self.instruction_pointer += 1;
+        Ok(())
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.registers[r],
+            Operand::Value(x) => x,
+        }
+    }
+
+    /* Move the instruction pointer by the offset, if it stays within the
+     * program (or right after its end, to finish it). */
+    fn jump(&mut self, offset: i64) -> std::result::Result<(), Halt> {
+        let from = self.instruction_pointer;
+
+        match (from as i64).checked_add(offset) {
+            Some(target) if (0..=self.memory.len() as i64).contains(&target) => {
+                self.instruction_pointer = target as usize;
+                Ok(())
+            },
+            _ => Err(Halt::JumpedOutOfBounds { from, target: (from as i64).saturating_add(offset) }),
+        }
+    }
+}
+
+/// Instruction executed by the debugger, with the registers before its execution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    /// Index of the instruction.
+    pub instruction_pointer: usize,
+    /// Instruction executed.
+    pub instruction: Instruction,
+    /// Registers before executing the instruction.
+    pub registers: Vec<i64>,
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>4}  {:<12}", self.instruction_pointer, self.instruction.to_string())?;
+        for (register, value) in self.registers.iter().enumerate() {
+            write!(f, "  {}={}", register_name(register), value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why the debugger gave back control.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stop {
+    /// A single instruction was executed.
+    Step,
+    /// The program halted.
+    Halted(Halt),
+    /// The instruction pointer reached a breakpoint (the instruction isn't executed yet).
+    Breakpoint(usize),
+    /// The last instruction changed a watched register.
+    Watchpoint {
+        /// Index of the register.
+        register: usize,
+        /// Value before the instruction.
+        old: i64,
+        /// Value after the instruction.
+        new: i64,
+    },
+}
+
+/// Runs a computer step by step, stopping at breakpoints (on instruction indices) and when
+/// watched registers change, and optionally keeping a trace of the executed instructions.
+#[derive(Debug)]
+pub struct Debugger {
+    /// Computer being debugged.
+    pub computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<usize>,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl Debugger {
+    /// Debug a computer, with no breakpoints or watchpoints, and tracing off.
+    pub fn new(computer: Computer) -> Debugger {
+        Debugger {
+            computer,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            trace: None,
+        }
+    }
+
+    /// Stop before executing the instruction at the index. Returns whether it is a new breakpoint.
+    pub fn set_breakpoint(&mut self, instruction_pointer: usize) -> bool {
+        self.breakpoints.insert(instruction_pointer)
+    }
+
+    /// Remove a breakpoint, returning whether there was one.
+    pub fn clear_breakpoint(&mut self, instruction_pointer: usize) -> bool {
+        self.breakpoints.remove(&instruction_pointer)
+    }
+
+    /// Indices of the instructions with a breakpoint, in order.
+    pub fn breakpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.breakpoints.iter().cloned()
+    }
+
+    /// Stop when the register changes, returning `None` if it doesn't exist.
+    pub fn watch(&mut self, register: usize) -> Option<()> {
+        if register >= self.computer.registers.len() {
+            return None;
+        }
+        self.watchpoints.insert(register);
+        Some(())
+    }
+
+    /// Stop watching a register, returning whether it was watched.
+    pub fn unwatch(&mut self, register: usize) -> bool {
+        self.watchpoints.remove(&register)
+    }
+
+    /// Indices of the watched registers, in order.
+    pub fn watchpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.watchpoints.iter().cloned()
+    }
+
+    /// Start or stop recording the executed instructions. Stopping drops the trace.
+    pub fn set_tracing(&mut self, tracing: bool) {
+        match (tracing, &self.trace) {
+            (true, None) => self.trace = Some(Vec::new()),
+            (false, _) => self.trace = None,
+            _ => (),
+        }
+    }
+
+    /// Instructions executed since tracing started, oldest first.
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or(&[])
+    }
+
+    /// Execute a single instruction, ignoring the breakpoints.
+    pub fn step(&mut self) -> Stop {
+        let ip = self.computer.instruction_pointer;
+        let registers = self.computer.registers.clone();
+
+        if let Err(halt) = self.computer.step() {
+            return Stop::Halted(halt);
+        }
+
+        let stop = self.watchpoints.iter()
+            .map(|&register| (register, registers[register], self.computer.registers[register]))
+            .find(|(_, old, new)| old != new)
+            .map_or(Stop::Step, |(register, old, new)| Stop::Watchpoint { register, old, new });
+
+        if let Some(trace) = &mut self.trace {
+            let instruction = self.computer.memory[ip];
+            trace.push(TraceEntry { instruction_pointer: ip, instruction, registers });
+        }
+
+        stop
+    }
+
+    /// Run until the program halts, a watched register changes, a breakpoint is reached, or the
+    /// run reaches one of the limits. The instruction at the current breakpoint is executed, to
+    /// resume after stopping there.
+    pub fn run(&mut self, limits: &Limits) -> Stop {
+        let mut states = HashSet::new();
+        let mut steps = 0;
+
+        loop {
+            let ip = self.computer.instruction_pointer;
+            if steps > 0 && self.breakpoints.contains(&ip) {
+                return Stop::Breakpoint(ip);
+            }
+            if ip < self.computer.memory.len() {
+                if limits.max_steps.is_some_and(|max| steps >= max) {
+                    return Stop::Halted(Halt::StepLimit(steps));
+                }
+                if limits.detect_loops && !states.insert((ip, self.computer.registers.clone())) {
+                    return Stop::Halted(Halt::LoopDetected(steps));
+                }
+            }
+
+            match self.step() {
+                Stop::Step => (),
+                stop => return stop,
+            }
+            steps += 1;
+        }
+    }
+}
+
+/// Where control can go after a basic block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Successor {
+    /// The block starting at this instruction.
+    Block(usize),
+    /// Out of the program, which halts.
+    Exit,
+    /// Anywhere, as the jump offset is read from a register.
+    Unknown,
+}
+
+impl fmt::Display for Successor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Successor::Block(start) => write!(f, "B{}", start),
+            Successor::Exit => write!(f, "exit"),
+            Successor::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+/// Instructions `start..end` of a program, which are always executed in sequence: only the first
+/// one is the target of jumps, and only the last one jumps.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasicBlock {
+    /// Index of the first instruction.
+    pub start: usize,
+    /// Index after the last instruction.
+    pub end: usize,
+    /// Where the execution can go after the block.
+    pub successors: Vec<Successor>,
+}
+
+/// Control-flow graph of a program: its basic blocks, and the loops found by following the jumps
+/// from its start.
+#[derive(Clone, Debug)]
+pub struct ControlFlowGraph<'a> {
+    memory: &'a [Instruction],
+    /// Blocks, by increasing start.
+    pub blocks: Vec<BasicBlock>,
+    /// Starts of the blocks which are jumped back to, i.e. the headers of the loops.
+    pub loop_headers: BTreeSet<usize>,
+}
+
+impl<'a> ControlFlowGraph<'a> {
+    /// Split a program into basic blocks, and find its loops.
+    pub fn new(memory: &'a [Instruction]) -> ControlFlowGraph<'a> {
+        let target = |address: usize, offset: JumpOffset| match offset.target(address) {
+            Some(target) if target < memory.len() => Successor::Block(target),
+            _ => Successor::Exit,
+        };
+        let next = |address: usize| target(address, JumpOffset::Positive(1));
+
+        /* Where control goes after each instruction, if not to the next one. */
+        let jumps: Vec<Option<Vec<Successor>>> = memory.iter().enumerate()
+            .map(|(address, instruction)| match *instruction {
+                Instruction::Jump(x) => Some(vec![target(address, x)]),
+                Instruction::JumpIfEven(_, x) | Instruction::JumpIfOne(_, x) =>
+                    Some(vec![next(address), target(address, x)]),
+                Instruction::JumpIfNotZero(Operand::Value(0), _) => Some(vec![next(address)]),
+                Instruction::JumpIfNotZero(x, y) => {
+                    let jump = match y {
+                        Operand::Value(y) => target(address, JumpOffset::from(y)),
+                        Operand::Register(_) => Successor::Unknown,
+                    };
+                    match x {
+                        Operand::Value(_) => Some(vec![jump]),
+                        Operand::Register(_) => Some(vec![next(address), jump]),
+                    }
+                },
+                _ => None,
+            })
+            .collect();
+
+        /* Blocks start at the start of the program, at jump targets, and after jumps. */
+        let mut starts: BTreeSet<usize> = BTreeSet::new();
+        if !memory.is_empty() {
+            starts.insert(0);
+        }
+        for (address, successors) in jumps.iter().enumerate() {
+            if let Some(successors) = successors {
+                starts.extend(successors.iter().filter_map(|s| match s {
+                    Successor::Block(start) => Some(*start),
+                    _ => None,
+                }));
+                if address + 1 < memory.len() {
+                    starts.insert(address + 1);
+                }
+            }
+        }
+
+        let starts: Vec<usize> = starts.into_iter().collect();
+        let blocks: Vec<BasicBlock> = starts.iter().enumerate()
+            .map(|(idx, &start)| {
+                let end = starts.get(idx + 1).cloned().unwrap_or(memory.len());
+                let successors = jumps[end - 1].clone().unwrap_or_else(|| vec![next(end - 1)]);
+                BasicBlock { start, end, successors }
+            })
+            .collect();
+
+        let mut graph = ControlFlowGraph { memory, blocks, loop_headers: BTreeSet::new() };
+        graph.loop_headers = graph.find_loop_headers();
+        graph
+    }
+
+    /// Block containing an instruction.
+    pub fn block(&self, address: usize) -> Option<&BasicBlock> {
+        let idx = match self.blocks.binary_search_by_key(&address, |block| block.start) {
+            Ok(idx) => idx,
+            Err(idx) => idx.checked_sub(1)?,
+        };
+        self.blocks.get(idx).filter(|block| address < block.end)
+    }
+
+    /* Walk the graph depth first from the start of the program: the jumps
+     * back to a block still being explored close loops. */
+    fn find_loop_headers(&self) -> BTreeSet<usize> {
+        let mut headers = BTreeSet::new();
+        let mut in_progress = HashSet::new();
+        let mut done = HashSet::new();
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+
+        if let Some(block) = self.blocks.first() {
+            in_progress.insert(block.start);
+            stack.push((block.start, 0));
+        }
+
+        while let Some((start, idx)) = stack.pop() {
+            let block = self.block(start).unwrap();
+            match block.successors.get(idx) {
+                Some(&Successor::Block(next)) => {
+                    stack.push((start, idx + 1));
+                    if in_progress.contains(&next) {
+                        headers.insert(next);
+                    } else if !done.contains(&next) {
+                        in_progress.insert(next);
+                        stack.push((next, 0));
+                    }
+                },
+                Some(_) => stack.push((start, idx + 1)),
+                None => {
+                    in_progress.remove(&start);
+                    done.insert(start);
+                },
+            }
+        }
+
+        headers
+    }
+
+    /// Recognize known idioms at the loop headers.
+    pub fn idioms(&self) -> Vec<Idiom> {
+        self.loop_headers.iter()
+            .filter_map(|&header| Idiom::recognize(self.memory, header))
+            .collect()
+    }
+
+    /// Export the graph to the DOT language of Graphviz, with the instructions of each block.
+    /// Loop headers are drawn with a double border.
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph program {\n");
+
+        for block in &self.blocks {
+            let label: String = (block.start..block.end)
+                .map(|address| format!("{}: {}\\l", address, self.memory[address]))
+                .collect();
+            let peripheries = if self.loop_headers.contains(&block.start) { ", peripheries=2" } else { "" };
+            writeln!(output, "    B{} [shape=box{}, label=\"{}\"];", block.start, peripheries, label).unwrap();
+
+            for successor in &block.successors {
+                match successor {
+                    Successor::Block(next) => writeln!(output, "    B{} -> B{};", block.start, next),
+                    Successor::Exit => writeln!(output, "    B{} -> exit;", block.start),
+                    Successor::Unknown => writeln!(output, "    B{} -> unknown [style=dashed];", block.start),
+                }.unwrap();
+            }
+        }
+
+        output.push_str("}\n");
+        output
+    }
+}
+
+impl fmt::Display for ControlFlowGraph<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for block in &self.blocks {
+            write!(f, "B{}:", block.start)?;
+            if self.loop_headers.contains(&block.start) {
+                write!(f, " (loop header)")?;
+            }
+            writeln!(f)?;
+
+            for address in block.start..block.end {
+                writeln!(f, "{:>6}  {}", address, self.memory[address])?;
+            }
+
+            let successors: Vec<String> = block.successors.iter().map(|s| s.to_string()).collect();
+            writeln!(f, "    -> {}", successors.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Loop computing something which can be done at once, instead of instruction by instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Idiom {
+    /// Count in `counter` the steps of the Collatz sequence (halve `value` if even, or else
+    /// triple it and add 1) until `value` is 1, as the program of day 23 does:
+    ///
+    /// ```text
+    /// header: jio value, +8
+    ///         inc counter
+    ///         jie value, +4
+    ///         tpl value
+    ///         inc value
+    ///         jmp +2
+    ///         hlf value
+    ///         jmp -7
+    /// ```
+    Collatz {
+        /// Index of the first instruction of the loop.
+        header: usize,
+        /// Register holding the value of the sequence.
+        value: usize,
+        /// Register counting the steps.
+        counter: usize,
+    },
+}
+
+impl Idiom {
+    /// Recognize an idiom at the header of a loop.
+    pub fn recognize(memory: &[Instruction], header: usize) -> Option<Idiom> {
+        use Instruction::*;
+        use JumpOffset::*;
+
+        match memory.get(header..header + 8)? {
+            &[JumpIfOne(r0, Positive(8)), Increment(counter), JumpIfEven(r1, Positive(4)), Triple(r2),
+              Increment(r3), Jump(Positive(2)), Half(r4), Jump(Negative(7))]
+                if [r1, r2, r3, r4].iter().all(|&r| r == r0) && counter != r0 =>
+                    Some(Idiom::Collatz { header, value: r0, counter }),
+            _ => None,
+        }
+    }
+
+    /// First instruction of the idiom.
+    pub fn header(&self) -> usize {
+        match self {
+            Idiom::Collatz { header, .. } => *header,
+        }
+    }
+
+    /// Execute the idiom at once, if the instruction pointer is at its header. Returns `None`
+    /// otherwise, or if the idiom can't be executed at once (e.g. if a value overflows).
+    pub fn fast_forward(&self, computer: &mut Computer) -> Option<()> {
+        if computer.instruction_pointer != self.header() {
+            return None;
+        }
+
+        match *self {
+            Idiom::Collatz { header, value, counter } => {
+                /* The sequence never reaches 1 from 0 or below. */
+                let mut n = computer.registers[value];
+                if n < 1 {
+                    return None;
+                }
+
+                let mut steps: i64 = 0;
+                while n != 1 {
+                    n = if n % 2 == 0 { n / 2 } else { n.checked_mul(3)?.checked_add(1)? };
+                    steps += 1;
+                }
+
+                computer.registers[value] = n;
+                computer.registers[counter] = computer.registers[counter].wrapping_add(steps);
+                computer.instruction_pointer = header + 8;
+            },
+        }
+
+        Some(())
+    }
+}
+
+impl fmt::Display for Idiom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Idiom::Collatz { header, value, counter } =>
+                write!(f, "{}: Collatz sequence of `{}`, whose steps until 1 are counted in `{}`.",
+                    header, register_name(*value), register_name(*counter)),
+        }
+    }
+}
+
+/* Instruction of a compiled program, where the jumps go to absolute indices:
+ * past the end of the program if they halt it, see `CompiledProgram::exits`. */
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Half(usize),
+    Triple(usize),
+    Increment(usize),
+    Decrement(usize),
+    Copy(Operand, usize),
+    Add(Operand, usize),
+    Multiply(Operand, usize),
+    Out(Operand),
+    Jump(usize),
+    JumpIfEven(usize, usize),
+    JumpIfOne(usize, usize),
+    JumpIfNotZero(Operand, usize),
+    /* The offset is read from a register, so the target is only known when
+     * jumping. */
+    JumpIfNotZeroBy(Operand, usize),
+}
+
+/// Program compiled once to be executed faster than by `Computer::run`: the jumps go to absolute
+/// indices, which are checked when compiling.
+#[derive(Clone, Debug)]
+pub struct CompiledProgram {
+    ops: Vec<Op>,
+    /// How the program halts when jumping to `ops.len() + 1 + idx`.
+    exits: Vec<Halt>,
+}
+
+impl CompiledProgram {
+    /// Compile a program.
+    pub fn new(memory: &[Instruction]) -> CompiledProgram {
+        let mut exits = Vec::new();
+        let mut target = |from: usize, offset: i64| match (from as i64).checked_add(offset) {
+            Some(target) if (0..=memory.len() as i64).contains(&target) => target as usize,
+            _ => {
+                exits.push(Halt::JumpedOutOfBounds { from, target: (from as i64).saturating_add(offset) });
+                memory.len() + exits.len()
+            },
+        };
+
+        let ops = memory.iter().enumerate()
+            .map(|(address, instruction)| match *instruction {
+                Instruction::Half(r) => Op::Half(r),
+                Instruction::Triple(r) => Op::Triple(r),
+                Instruction::Increment(r) => Op::Increment(r),
+                Instruction::Decrement(r) => Op::Decrement(r),
+                Instruction::Copy(x, r) => Op::Copy(x, r),
+                Instruction::Add(x, r) => Op::Add(x, r),
+                Instruction::Multiply(x, r) => Op::Multiply(x, r),
+                Instruction::Out(x) => Op::Out(x),
+                Instruction::Jump(x) => Op::Jump(target(address, x.value())),
+                Instruction::JumpIfEven(r, x) => Op::JumpIfEven(r, target(address, x.value())),
+                Instruction::JumpIfOne(r, x) => Op::JumpIfOne(r, target(address, x.value())),
+                Instruction::JumpIfNotZero(x, Operand::Value(y)) => Op::JumpIfNotZero(x, target(address, y)),
+                Instruction::JumpIfNotZero(x, Operand::Register(r)) => Op::JumpIfNotZeroBy(x, r),
+            })
+            .collect();
+
+        CompiledProgram { ops, exits }
+    }
+
+    /// Run the program on the computer, from its instruction pointer and with its registers, like
+    /// `Computer::run` would run it (the memory of the computer is ignored).
+    pub fn run(&self, computer: &mut Computer, limits: &Limits) -> Halt {
+        let Computer { registers, output, instruction_pointer, .. } = computer;
+        let len = self.ops.len();
+        let max_steps = limits.max_steps.unwrap_or(usize::MAX);
+        let mut states = HashSet::new();
+        let mut steps = 0;
+        let mut ip = *instruction_pointer;
+
+        let value = |registers: &[i64], operand: Operand| match operand {
+            Operand::Register(r) => registers[r],
+            Operand::Value(x) => x,
+        };
+
+        let halt = loop {
+            let op = match self.ops.get(ip) {
+                Some(op) => *op,
+                None => break Halt::Finished,
+            };
+            if steps >= max_steps {
+                break Halt::StepLimit(steps);
+            }
+            if limits.detect_loops && !states.insert((ip, registers.clone())) {
+                break Halt::LoopDetected(steps);
+            }
+            steps += 1;
+
+            ip = match op {
+                Op::Half(r) => { registers[r] /= 2; ip + 1 },
+                Op::Triple(r) => { registers[r] = registers[r].wrapping_mul(3); ip + 1 },
+                Op::Increment(r) => { registers[r] = registers[r].wrapping_add(1); ip + 1 },
+                Op::Decrement(r) => { registers[r] = registers[r].wrapping_sub(1); ip + 1 },
+                Op::Copy(x, r) => { registers[r] = value(registers, x); ip + 1 },
+                Op::Add(x, r) => { registers[r] = registers[r].wrapping_add(value(registers, x)); ip + 1 },
+                Op::Multiply(x, r) => { registers[r] = registers[r].wrapping_mul(value(registers, x)); ip + 1 },
+                Op::Out(x) => { output.push(value(registers, x)); ip + 1 },
+                Op::Jump(target) => target,
+                Op::JumpIfEven(r, target) => if registers[r] % 2 == 0 { target } else { ip + 1 },
+                Op::JumpIfOne(r, target) => if registers[r] == 1 { target } else { ip + 1 },
+                Op::JumpIfNotZero(x, target) => if value(registers, x) != 0 { target } else { ip + 1 },
+                Op::JumpIfNotZeroBy(x, r) if value(registers, x) != 0 => {
+                    match (ip as i64).checked_add(registers[r]) {
+                        Some(target) if (0..=len as i64).contains(&target) => target as usize,
+                        _ => {
+                            let target = (ip as i64).saturating_add(registers[r]);
+                            break Halt::JumpedOutOfBounds { from: ip, target };
+                        },
+                    }
+                },
+                Op::JumpIfNotZeroBy(..) => ip + 1,
+            };
+
+            if ip > len {
+                break self.exits[ip - len - 1];
+            }
+        };
+
+        /* The instruction pointer stays on the jumps out of the program. */
+        *instruction_pointer = match halt {
+            Halt::JumpedOutOfBounds { from, .. } => from,
+            _ => ip,
+        };
+        halt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_program() {
+        let mut computer = Computer::new();
+        computer.load_program("inc a\njio a, +2\ntpl a\ninc a\n").unwrap();
+        assert_eq!(computer.execute_program(), Halt::Finished);
+        assert_eq!(computer.registers, [2, 0]);
+    }
+
+    #[test]
+    fn assembler_round_trip() {
+        let program = "jio a, +3\ninc a\njmp +4\nhlf b\njie b, -3\njmp -6\n";
+        let memory = assemble(program).unwrap();
+
+        let source = disassemble(&memory);
+        assert_eq!(source, "        jio a, L3\n\
+                            L1:     inc a\n\
+                            \x20       jmp L6\n\
+                            L3:     hlf b\n\
+                            \x20       jie b, L1\n\
+                            \x20       jmp -6\n\
+                            L6:\n");
+        assert_eq!(assemble(&source).unwrap(), memory);
+
+        let source = "start: inc a ; comment\n\n  jio a, end\njmp start\nend:\n";
+        let memory = assemble(source).unwrap();
+        assert_eq!(memory, assemble("inc a\njio a, +2\njmp -2\n").unwrap());
+
+        assert_eq!(assemble("jmp end\n").unwrap_err().column, 5);
+        assert_eq!(assemble("x:\nx: inc a\n").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn assembunny() {
+        let mut computer = Computer::with_instruction_set(InstructionSet::assembunny());
+        computer.load_program("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a\n").unwrap();
+        assert_eq!(computer.execute_program(), Halt::Finished);
+        assert_eq!(computer.registers, [42, 0, 0, 0]);
+
+        let program = "cpy -3 b\nloop: mul b c\nadd 2 d\nout d\ninc b\njnz b loop\njnz 1 -9\n";
+        let mut computer = Computer::with_instruction_set(InstructionSet::assembunny());
+        computer.load_program(program).unwrap();
+        assert_eq!(computer.execute_program(), Halt::JumpedOutOfBounds { from: 6, target: -3 });
+        assert_eq!(computer.output, [2, 4, 6]);
+        let source = disassemble(&computer.memory);
+        assert_eq!(computer.instruction_set.assemble(&source).unwrap(), computer.memory);
+
+        assert_eq!(Computer::new().load_program("cpy 1 a\n").unwrap_err().column, 1);
+        assert_eq!(computer.instruction_set.read_instruction("cpy 1 e").unwrap_err().column, 7);
+    }
+
+    #[test]
+    fn control_flow_graph() {
+        let program = "inc b\njio a, +3\ntpl a\njmp -3\nhlf a\n\
+            jio a, +8\ninc b\njie a, +4\ntpl a\ninc a\njmp +2\nhlf a\njmp -7\n";
+        let memory = assemble(program).unwrap();
+        let graph = ControlFlowGraph::new(&memory);
+
+        let blocks: Vec<(usize, usize)> = graph.blocks.iter().map(|b| (b.start, b.end)).collect();
+        assert_eq!(blocks, [(0, 2), (2, 4), (4, 5), (5, 6), (6, 8), (8, 11), (11, 12), (12, 13)]);
+        assert_eq!(graph.block(3).unwrap().successors, [Successor::Block(0)]);
+        assert_eq!(graph.block(5).unwrap().successors, [Successor::Block(6), Successor::Exit]);
+        assert_eq!(graph.loop_headers.iter().cloned().collect::<Vec<_>>(), [0, 5]);
+        assert_eq!(graph.idioms(), [Idiom::Collatz { header: 5, value: 0, counter: 1 }]);
+        assert!(graph.to_dot().contains("    B5 [shape=box, peripheries=2, label=\"5: jio a, +8\\l\"];\n"));
+
+        for &a in &[0, 1, 7, 27] {
+            let mut computer = Computer::new();
+            computer.load_program(program).unwrap();
+            computer.set_register(0, a);
+            let mut fast = Computer::new();
+            fast.load_program(program).unwrap();
+            fast.set_register(0, a);
+
+            let limits = Limits { max_steps: Some(10_000), detect_loops: true };
+            assert_eq!(fast.run_with_idioms(&limits, &graph.idioms()) == Halt::Finished,
+                computer.run(&limits) == Halt::Finished);
+            assert_eq!(fast.registers, computer.registers);
+        }
+    }
+
+    #[test]
+    fn compiled_program() {
+        let programs = [
+            (InstructionSet::day23(), "jio a, +3\ninc b\njmp +2\ntpl b\njie a, -4\njmp +1\n"),
+            (InstructionSet::day23(), "inc a\njio a, -2\n"),
+            (InstructionSet::day23(), "hlf a\njmp -1\n"),
+            (InstructionSet::assembunny(), "cpy 3 c\nloop: out c\ndec c\ncpy -2 d\njnz c d\nmul 7 a\njnz a 5\n"),
+            (InstructionSet::assembunny(), "cpy 2 d\njnz 1 d\nout 1\nadd a d\ncpy -9 b\njnz d b\n"),
+            /* Jumps whose target overflows an `i64`. */
+            (InstructionSet::day23(), "inc a\njmp +9223372036854775807\n"),
+            (InstructionSet::day23(), "inc a\njio a, +9223372036854775807\n"),
+            (InstructionSet::assembunny(), "inc a\njnz 1 9223372036854775807\n"),
+            (InstructionSet::assembunny(), "cpy 9223372036854775807 b\njnz 1 b\n"),
+        ];
+        let limits = Limits { max_steps: Some(1_000), detect_loops: true };
+
+        for (set, program) in programs.iter() {
+            for a in 0..4 {
+                let mut computer = Computer::with_instruction_set(set.clone());
+                computer.load_program(program).unwrap();
+                computer.set_register(0, a);
+                let mut compiled = Computer::with_instruction_set(set.clone());
+                compiled.load_program(program).unwrap();
+                compiled.set_register(0, a);
+
+                let halt = CompiledProgram::new(&compiled.memory).run(&mut compiled, &limits);
+                assert_eq!(halt, computer.run(&limits), "{} with a = {}", program, a);
+                assert_eq!(compiled.registers, computer.registers);
+                assert_eq!(compiled.instruction_pointer, computer.instruction_pointer);
+                assert_eq!(compiled.output, computer.output);
+            }
+        }
+    }
+
+    #[test]
+    fn halts() {
+        let run = |program: &str, limits: &Limits| {
+            let mut computer = Computer::new();
+            computer.load_program(program).unwrap();
+            computer.run(limits)
+        };
+        let limits = Limits { max_steps: Some(10), detect_loops: true };
+
+        assert_eq!(run("inc a\njmp -2\n", &limits), Halt::JumpedOutOfBounds { from: 1, target: -1 });
+        assert_eq!(run("jmp +3\n", &limits), Halt::JumpedOutOfBounds { from: 0, target: 3 });
+        assert_eq!(run("jmp +1\n", &limits), Halt::Finished);
+        assert!(Computer::read_instruction("jmp +18446744073709551615").is_err());
+        assert_eq!(JumpOffset::Positive(usize::MAX).value(), i64::MAX);
+        assert_eq!(JumpOffset::new("-9223372036854775807").unwrap().value(), -i64::MAX);
+        assert_eq!(run("inc a\njmp +9223372036854775807\n", &limits),
+            Halt::JumpedOutOfBounds { from: 1, target: i64::MAX });
+        assert_eq!(run("inc a\njio a, +9223372036854775807\n", &limits),
+            Halt::JumpedOutOfBounds { from: 1, target: i64::MAX });
+        assert_eq!(run("hlf a\njmp -1\n", &limits), Halt::LoopDetected(2));
+        assert_eq!(run("inc a\njmp -1\n", &limits), Halt::StepLimit(10));
+    }
+
+    #[test]
+    fn debugger() {
+        let mut computer = Computer::new();
+        computer.load_program("inc a\njio a, +2\ntpl a\ninc a\ninc b\n").unwrap();
+        let mut debugger = Debugger::new(computer);
+        debugger.set_tracing(true);
+        debugger.set_breakpoint(3);
+        debugger.watch(register("b").unwrap()).unwrap();
+
+        assert_eq!(debugger.step(), Stop::Step);
+        let limits = Limits::default();
+        assert_eq!(debugger.run(&limits), Stop::Breakpoint(3));
+        assert_eq!(debugger.computer.registers, [1, 0]);
+        assert_eq!(debugger.run(&limits), Stop::Watchpoint { register: 1, old: 0, new: 1 });
+        assert_eq!(debugger.run(&limits), Stop::Halted(Halt::Finished));
+
+        let trace: Vec<String> = debugger.trace().iter().map(|entry| entry.to_string()).collect();
+        assert_eq!(trace, [
+            "   0  inc a         a=0  b=0",
+            "   1  jio a, +2     a=1  b=0",
+            "   3  inc a         a=1  b=0",
+            "   4  inc b         a=2  b=0",
+        ]);
+
+        /* Programs which never halt stop at the limits. */
+        let mut computer = Computer::new();
+        computer.load_program("inc a\njmp +0\n").unwrap();
+        let mut debugger = Debugger::new(computer);
+        let limits = Limits { max_steps: None, detect_loops: true };
+        assert_eq!(debugger.run(&limits), Stop::Halted(Halt::LoopDetected(2)));
+        debugger.set_breakpoint(1);
+        assert_eq!(debugger.run(&limits), Stop::Breakpoint(1));
+        debugger.clear_breakpoint(1);
+        let limits = Limits { max_steps: Some(5), detect_loops: false };
+        assert_eq!(debugger.run(&limits), Stop::Halted(Halt::StepLimit(5)));
+    }
+}
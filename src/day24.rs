@@ -0,0 +1,73 @@
+//! Day 24: It Hangs in the Balance (<https://adventofcode.com/2015/day/24>).
+
+use crate::error::{parse_token, ParseError};
+use crate::solver::{Result, Solver};
+use itertools::Itertools;
+
+/// Santa's sleigh must be balanced: the packages are split into groups of equal weights, and the
+/// group in the passenger compartment needs as few packages as possible.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        solve(input, 3)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        solve(input, 4)
+    }
+}
+
+fn solve(input: &str, nbr_groups: usize) -> Result<String> {
+    part1(&read_input(input)?, nbr_groups)
+        .map(|x| x.to_string())
+        .ok_or_else(|| format!("The packages can't be split into {} groups of equal weight.", nbr_groups)
+            .into())
+}
+
+/// Read the weights of the packages, one per line.
+pub fn read_input(contents: &str) -> std::result::Result<Vec<usize>, ParseError> {
+    contents.lines()
+        .enumerate()
+        .map(|(idx, line)| parse_token(line, line).map_err(|e| e.at_line(idx + 1)))
+        .collect()
+}
+
+/// Quantum entanglement (product of the weights) of the first group, in the ideal configuration
+/// with `nbr_groups` groups, or `None` if the total weight can't be split evenly.
+///
+/// We are assuming that the remaining groups can be split evenly. A recursive call with the
+/// remaining packages would be the better solution.
+pub fn part1(weights: &[usize], nbr_groups: usize) -> Option<usize> {
+    let total_weight: usize = weights.iter().sum();
+    if weights.is_empty() || nbr_groups == 0 || !total_weight.is_multiple_of(nbr_groups) {
+        return None;
+    }
+    let target_weight = total_weight / nbr_groups;
+
+    (1..=weights.len()).find_map(|size| {
+        weights.iter()
+                        .combinations(size)
+                        .filter_map(|c|
+                            if c.iter().copied().sum::<usize>() == target_weight {
+                                Some(c.into_iter().product())
+                            } else {
+                                None
+                            })
+                        .min()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantum_entanglement() {
+        let weights = read_input("1\n2\n3\n4\n5\n7\n8\n9\n10\n11\n").unwrap();
+        assert_eq!(part1(&weights, 3), Some(99));
+        assert_eq!(part1(&weights, 4), Some(44));
+        assert_eq!(part1(&weights, 7), None);
+        assert_eq!(part1(&[], 3), None);
+    }
+}
@@ -0,0 +1,115 @@
+//! Day 25: Let It Snow (<https://adventofcode.com/2015/day/25>).
+
+use crate::error::{next_token, parse_token, ParseError};
+use crate::solver::{Part, Result, Solver};
+use std::iter;
+
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+const SEED: u64 = 20151125;
+
+/// The weather machine needs a code, found in an infinite grid filled diagonally by a pseudorandom
+/// generator. There is only one puzzle on the last day.
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    fn part1(&self, input: &str) -> Result<String> {
+        let (row, column) = read_input(input)?;
+
+        /* Iterating starts with the second code. */
+        let answer =
+            iter::once(CodeGenerator::new()).chain(CodeGenerator::new())
+                .find(|c| c.row == row && c.column == column)
+                .unwrap()
+                .current;
+
+        Ok(answer.to_string())
+    }
+
+    fn part2(&self, _input: &str) -> Result<String> {
+        Err("There is no second part on the last day.".into())
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[Part::One]
+    }
+}
+
+/// Read the row and column of the code (e.g. `Enter the code at row 3010, column 3019.`).
+pub fn read_input(contents: &str) -> std::result::Result<(usize, usize), ParseError> {
+    let line = contents.trim_end();
+    let idx = line.find(|c: char| c.is_ascii_digit())
+        .ok_or_else(|| ParseError::missing(line, "Missing row."))?;
+
+    let mut iter = line[idx..].split_whitespace();
+    let row = read_position(line, next_token(&mut iter, line, "row")?.trim_end_matches(','))?;
+    let column = next_token(&mut iter.by_ref().skip(1), line, "column")?.trim_end_matches('.');
+    let column = read_position(line, column)?;
+
+    Ok((row, column))
+}
+
+/* Read a row or column, which start at 1. */
+fn read_position(line: &str, token: &str) -> std::result::Result<usize, ParseError> {
+    match parse_token(line, token)? {
+        0 => Err(ParseError::new(line, token, "Rows and columns start at 1.")),
+        x => Ok(x),
+    }
+}
+
+/// Position and value of the current code. Iterating goes to the next code.
+#[derive(Clone)]
+pub struct CodeGenerator {
+    /// Row of the code, starting at 1.
+    pub row: usize,
+    /// Column of the code, starting at 1.
+    pub column: usize,
+    /// Value of the code.
+    pub current: u64,
+}
+
+impl Default for CodeGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator {
+    /// Start at the first code, on the first row and column.
+    pub fn new() -> CodeGenerator {
+        CodeGenerator { row: 1, column: 1, current: SEED }
+    }
+}
+
+impl Iterator for CodeGenerator {
+    type Item = CodeGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.row {
+            1 => { self.row = self.column + 1; self.column = 1; },
+            _ => { self.row -= 1; self.column += 1; },
+        }
+
+        self.current = self.current * MULTIPLIER % MODULUS;
+        Some(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes() {
+        assert_eq!(CodeGenerator::new().current, 20151125);
+
+        let expected = [(1, 1, 20151125), (2, 1, 31916031), (1, 2, 18749137), (4, 4, 9380097),
+            (5, 1, 77061), (6, 6, 27995004)];
+
+        for &(row, column, code) in &expected {
+            let input = format!("Enter the code at row {}, column {}.", row, column);
+            assert_eq!(Puzzle.part1(&input).unwrap(), code.to_string());
+        }
+        assert!(read_input("Enter the code at row 0, column 3.").is_err());
+    }
+}
@@ -1,3 +1,6 @@
+//! Chemical elements, which make up the molecules of day 19.
+
+/// Chemical element, by its English name.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Element {
     Actinium,
@@ -123,6 +126,7 @@ pub enum Element {
     Zirconium,
 }
 
+/// Element written with the given symbol (e.g. `Ca` for calcium), if any.
 pub fn symbol_to_element(symbol: &str) -> Option<Element> {
     match symbol {
         "Ac" => Some(Element::Actinium),
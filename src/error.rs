@@ -0,0 +1,167 @@
+//! Errors raised when reading the puzzle inputs, with the position of the offending token.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error raised when reading a puzzle input, with the position (line and column, both starting
+/// at 1) of the offending token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// File being read, if known.
+    pub file: Option<String>,
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error, in characters and starting at 1.
+    pub column: usize,
+    /// Offending token (empty when something is missing).
+    pub token: String,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error on `token`, which should be a slice of `line`. The line number defaults to 1, since
+    /// most readers work on a single line (see `at_line`).
+    pub fn new(line: &str, token: &str, message: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line: 1,
+            column: column(line, token),
+            token: token.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error on a token missing at the end of `line`.
+    pub fn missing(line: &str, message: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line: 1,
+            column: line.chars().count() + 1,
+            token: String::new(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error on the last of `keys` in a TOML document. The TOML parser doesn't keep the position
+    /// of the keys, so each key is searched for after the line where the previous one was found.
+    pub fn at_keys(contents: &str, keys: &[&str], message: &str) -> ParseError {
+        let mut lines = contents.lines().enumerate();
+        let mut error = ParseError::missing("", message);
+
+        for key in keys {
+            if let Some((idx, line)) = lines.by_ref().find(|(_, line)| line.contains(key)) {
+                error = ParseError::new(line, key, message).at_line(idx + 1);
+            }
+        }
+
+        error
+    }
+
+    /// Set the line number.
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    /// Move the error found in `part` (a slice of `line`) to its position in `line`.
+    pub fn within(mut self, line: &str, part: &str) -> ParseError {
+        self.column += column(line, part) - 1;
+        self
+    }
+
+    /// Set the name of the file being read.
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}: {}",
+            self.file.as_deref().unwrap_or("<input>"), self.line, self.column, self.message)?;
+
+        if !self.token.is_empty() {
+            write!(f, " (found `{}`)", self.token)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<serde_json::Error> for ParseError {
+    fn from(error: serde_json::Error) -> ParseError {
+        ParseError {
+            file: None,
+            line: error.line(),
+            column: error.column(),
+            token: String::new(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<toml::de::Error> for ParseError {
+    fn from(error: toml::de::Error) -> ParseError {
+        /* The TOML parser counts lines and columns from 0. */
+        let (line, column) = error.line_col().map_or((0, 0), |(l, c)| (l + 1, c + 1));
+
+        ParseError {
+            file: None,
+            line,
+            column,
+            token: String::new(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Parse `token`, found in `line`.
+pub fn parse_token<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::new(line, token, "Invalid number."))
+}
+
+/// Get the next token from `iter`, which splits `line`.
+pub fn next_token<'a, I>(iter: &mut I, line: &str, what: &str) -> Result<&'a str, ParseError>
+    where I: Iterator<Item = &'a str> {
+
+    iter.next().ok_or_else(|| ParseError::missing(line, &format!("Missing {}.", what)))
+}
+
+/* Column (in characters) of `token` in `line`. If `token` isn't a slice of
+ * `line`, look for it instead. */
+fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+
+    let offset = if position >= start && position + token.len() <= start + line.len() {
+        position - start
+    } else {
+        line.find(token).unwrap_or(0)
+    };
+
+    line[..offset].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_token() {
+        let line = "123 -> x";
+        let error = ParseError::new(line, &line[7..], "Invalid wire.").at_line(4);
+        assert_eq!((error.line, error.column, error.token.as_str()), (4, 8, "x"));
+    }
+
+    #[test]
+    fn display() {
+        let line = "x AND";
+        let error = ParseError::missing(line, "Missing input.").at_line(2).in_file("day7.txt");
+        assert_eq!(error.to_string(), "day7.txt:2:6: Missing input.");
+    }
+}
@@ -0,0 +1,152 @@
+//! Location of the puzzle inputs, and of the expected answers, on disk.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable giving the directory of the puzzle inputs.
+pub const DIR_VAR: &str = "AOC_INPUTS";
+/// Environment variable giving the profile whose inputs are used.
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+
+/// Finds the puzzle inputs, which are stored as `<dir>/day<day>.txt`, or as
+/// `<dir>/<profile>/day<day>.txt` to keep the inputs of several accounts side by
+/// side. The expected answers (`answers.toml`) sit next to the inputs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Inputs {
+    /// Directory of the inputs.
+    pub dir: PathBuf,
+    /// Subdirectory of the inputs of one account, if any.
+    pub profile: Option<String>,
+}
+
+impl Inputs {
+    /// Find the inputs in `dir`, for the given profile if any.
+    pub fn new<P: Into<PathBuf>>(dir: P, profile: Option<&str>) -> Inputs {
+        Inputs { dir: dir.into(), profile: profile.map(str::to_string) }
+    }
+
+    /// Resolve the directory and profile: the values given (from the command line) come first,
+    /// then the `AOC_INPUTS` and `AOC_PROFILE` environment variables. The directory defaults to
+    /// `inputs` in the working directory, or in the crate if there is none.
+    pub fn resolve(dir: Option<&str>, profile: Option<&str>) -> Inputs {
+        let dir = dir.map(PathBuf::from)
+            .or_else(|| env::var_os(DIR_VAR).map(PathBuf::from))
+            .unwrap_or_else(default_dir);
+        let profile = profile.map(str::to_string)
+            .or_else(|| env::var(PROFILE_VAR).ok())
+            .filter(|profile| !profile.is_empty());
+
+        Inputs { dir, profile }
+    }
+
+    /// Directory of the files of the profile.
+    pub fn profile_dir(&self) -> PathBuf {
+        match &self.profile {
+            Some(profile) => self.dir.join(profile),
+            None => self.dir.clone(),
+        }
+    }
+
+    /// Path of the puzzle input of the day.
+    pub fn path(&self, day: usize) -> PathBuf {
+        self.profile_dir().join(format!("day{}.txt", day))
+    }
+
+    /// Path of the expected answers.
+    pub fn answers_path(&self) -> PathBuf {
+        self.profile_dir().join("answers.toml")
+    }
+
+    /// Path of the spells of day 22, which replace the spells of the puzzle when the file exists.
+    pub fn spells_path(&self) -> PathBuf {
+        self.profile_dir().join("day22_spells.toml")
+    }
+
+    /// Read the puzzle input of the day.
+    pub fn read(&self, day: usize) -> Result<String, MissingInput> {
+        read_input(&self.path(day), Some(day), self.profile.as_deref())
+    }
+}
+
+/// Read a puzzle input, explaining where it was expected if it can't be read.
+pub fn read_input(path: &Path, day: Option<usize>, profile: Option<&str>) -> Result<String, MissingInput> {
+    fs::read_to_string(path).map_err(|error| MissingInput {
+        path: path.to_path_buf(),
+        day,
+        profile: profile.map(str::to_string),
+        error,
+    })
+}
+
+/* Use `inputs` in the working directory when it exists, so that the inputs can
+ * be swapped without rebuilding. Otherwise, fall back on the crate's inputs to
+ * run from anywhere. */
+fn default_dir() -> PathBuf {
+    let local = PathBuf::from("inputs");
+
+    if local.is_dir() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+    }
+}
+
+/// Error raised when a puzzle input can't be read.
+#[derive(Debug)]
+pub struct MissingInput {
+    /// File which couldn't be read.
+    pub path: PathBuf,
+    /// Day of the puzzle, if known.
+    pub day: Option<usize>,
+    /// Profile whose inputs were read, if any.
+    pub profile: Option<String>,
+    /// Error raised when reading the file.
+    pub error: io::Error,
+}
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "Can't read the input of day {}", day)?,
+            None => write!(f, "Can't read the input")?,
+        }
+        if let Some(profile) = &self.profile {
+            write!(f, " for profile `{}`", profile)?;
+        }
+        write!(f, " from {} ({}).", self.path.display(), self.error)?;
+
+        /* Only missing files need to be downloaded. */
+        if let (Some(day), io::ErrorKind::NotFound) = (self.day, self.error.kind()) {
+            write!(f, " Download it from <https://adventofcode.com/2015/day/{}/input>, or set \
+                the inputs directory with --inputs or {}, and the profile with --profile or {}.",
+                day, DIR_VAR, PROFILE_VAR)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for MissingInput {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_layout() {
+        let inputs = Inputs::resolve(Some("data"), Some("alice"));
+        assert_eq!(inputs.path(7), Path::new("data/alice/day7.txt"));
+        assert_eq!(inputs.answers_path(), Path::new("data/alice/answers.toml"));
+
+        let inputs = Inputs::new("data", None);
+        assert_eq!(inputs.path(19), Path::new("data/day19.txt"));
+    }
+}
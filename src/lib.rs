@@ -1 +1,75 @@
+//! Solutions to the Advent of code 2015 (<https://adventofcode.com/2015>).
+//!
+//! Every day has its own module (`day01` to `day25`), exposing the types used to solve the puzzle
+//! and a `Puzzle` implementing the `Solver` trait.
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
 pub mod elements;
+pub mod error;
+pub mod inputs;
+pub mod report;
+pub mod verify;
+mod solver;
+
+pub use crate::error::ParseError;
+pub use crate::solver::{Part, Result, Solver};
+
+/// Number of puzzles in the calendar.
+pub const NBR_DAYS: usize = 25;
+
+/// Get the solver for a given day (1 to 25).
+pub fn solver(day: usize) -> Option<&'static dyn Solver> {
+    match day {
+        1 => Some(&day01::Puzzle),
+        2 => Some(&day02::Puzzle),
+        3 => Some(&day03::Puzzle),
+        4 => Some(&day04::Puzzle),
+        5 => Some(&day05::Puzzle),
+        6 => Some(&day06::Puzzle),
+        7 => Some(&day07::Puzzle),
+        8 => Some(&day08::Puzzle),
+        9 => Some(&day09::Puzzle),
+        10 => Some(&day10::Puzzle),
+        11 => Some(&day11::Puzzle),
+        12 => Some(&day12::Puzzle),
+        13 => Some(&day13::Puzzle),
+        14 => Some(&day14::Puzzle),
+        15 => Some(&day15::Puzzle),
+        16 => Some(&day16::Puzzle),
+        17 => Some(&day17::Puzzle),
+        18 => Some(&day18::Puzzle),
+        19 => Some(&day19::Puzzle),
+        20 => Some(&day20::Puzzle),
+        21 => Some(&day21::Puzzle),
+        22 => Some(&day22::Puzzle),
+        23 => Some(&day23::Puzzle),
+        24 => Some(&day24::Puzzle),
+        25 => Some(&day25::Puzzle),
+        _ => None,
+    }
+}
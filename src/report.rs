@@ -0,0 +1,131 @@
+//! Answers to the puzzles, and how to print them.
+
+use crate::solver::{Part, Result, Solver};
+use serde_json::json;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Answer to one part of a puzzle, with the time it took to solve it.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    /// Day of the puzzle.
+    pub day: usize,
+    /// Part of the puzzle.
+    pub part: Part,
+    /// Answer to the puzzle.
+    pub value: String,
+    /// Time spent solving the puzzle.
+    pub duration: Duration,
+}
+
+impl Answer {
+    /// Solve one part of a puzzle, timing the solver.
+    pub fn solve(day: usize, solver: &dyn Solver, part: Part, input: &str) -> Result<Answer> {
+        let start = Instant::now();
+        let value = solver.solve(part, input)?;
+        let duration = start.elapsed();
+
+        Ok(Answer { day, part, value, duration })
+    }
+
+    /// Time taken to solve the puzzle, in milliseconds.
+    pub fn milliseconds(&self) -> f64 {
+        self.duration.as_secs_f64() * 1000.0
+    }
+}
+
+/// Output formats for the answers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Human-readable sentences ("The first answer is: ...").
+    Text,
+    /// Array of objects, with the `day`, `part`, `answer` and `time_ms` fields.
+    Json,
+    /// Tab-separated values, with a header line.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("Unknown format: {}.", s)),
+        }
+    }
+}
+
+impl Format {
+    /// Format a list of answers.
+    pub fn format(self, answers: &[Answer]) -> String {
+        match self {
+            Format::Text => to_text(answers),
+            Format::Json => to_json(answers),
+            Format::Tsv => to_tsv(answers),
+        }
+    }
+}
+
+fn to_text(answers: &[Answer]) -> String {
+    let mut output = String::new();
+    let several_days = answers.windows(2).any(|w| w[0].day != w[1].day);
+    let mut previous_day = None;
+
+    for answer in answers {
+        if several_days && previous_day != Some(answer.day) {
+            writeln!(output, "Day {}", answer.day).unwrap();
+            previous_day = Some(answer.day);
+        }
+        writeln!(output, "The {} answer is: {}", answer.part.ordinal(), answer.value).unwrap();
+    }
+
+    output
+}
+
+fn to_json(answers: &[Answer]) -> String {
+    let answers: Vec<_> = answers.iter()
+        .map(|answer| json!({
+            "day": answer.day,
+            "part": answer.part.number(),
+            "answer": answer.value,
+            "time_ms": answer.milliseconds(),
+        }))
+        .collect();
+
+    format!("{}\n", serde_json::to_string_pretty(&answers).unwrap())
+}
+
+/* Tabs and line feeds can't appear in the answers, since they would break the
+ * columns. */
+fn to_tsv(answers: &[Answer]) -> String {
+    let mut output = String::from("day\tpart\tanswer\ttime_ms\n");
+
+    for answer in answers {
+        let value = answer.value.replace(['\t', '\n'], " ");
+        writeln!(output, "{}\t{}\t{}\t{:.3}",
+            answer.day, answer.part, value, answer.milliseconds()).unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tsv_format() {
+        let answers = [Answer {
+            day: 16,
+            part: Part::Two,
+            value: String::from("Sue\t241"),
+            duration: Duration::from_millis(2),
+        }];
+
+        assert_eq!(Format::Tsv.format(&answers), "day\tpart\tanswer\ttime_ms\n16\t2\tSue 241\t2.000\n");
+    }
+}
@@ -0,0 +1,71 @@
+use std::error::Error;
+use std::fmt;
+
+/// Result type returned by the solvers.
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Each puzzle is split in two parts (except for the last day).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    /// First part of the puzzle.
+    One,
+    /// Second part of the puzzle.
+    Two,
+}
+
+impl Part {
+    /// Convert the number of a part (1 or 2) to a `Part`.
+    pub fn from_number(number: usize) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    /// Number of the part (1 or 2).
+    pub fn number(self) -> usize {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// Ordinal used when printing answers ("The first answer is: ...").
+    pub fn ordinal(self) -> &'static str {
+        match self {
+            Part::One => "first",
+            Part::Two => "second",
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Common interface to every day's puzzle. The input is the content of the puzzle input, and the
+/// answer is returned as a string, since a few puzzles (day 11 and day 16) don't have numerical
+/// answers.
+pub trait Solver {
+    /// Solve the first part of the puzzle.
+    fn part1(&self, input: &str) -> Result<String>;
+
+    /// Solve the second part of the puzzle.
+    fn part2(&self, input: &str) -> Result<String>;
+
+    /// Parts available for this puzzle.
+    fn parts(&self) -> &'static [Part] {
+        &[Part::One, Part::Two]
+    }
+
+    /// Solve the requested part of the puzzle.
+    fn solve(&self, part: Part, input: &str) -> Result<String> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Comparison of the answers with the expected ones.
+
+use crate::error::ParseError;
+use crate::report::Answer;
+use crate::solver::Part;
+use std::collections::HashMap;
+use std::fmt;
+use toml::Value;
+
+/// Expected answers, read from a TOML file with one table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = 280
+/// part2 = 1797
+///
+/// [day16]
+/// part1 = "Sue 40"
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ExpectedAnswers(HashMap<(usize, Part), String>);
+
+impl ExpectedAnswers {
+    /// Read the expected answers.
+    pub fn from_toml(contents: &str) -> Result<ExpectedAnswers, ParseError> {
+        let document: Value = contents.parse()?;
+
+        let mut answers = HashMap::new();
+        let days = document.as_table().into_iter().flatten();
+
+        for (day_key, parts) in days {
+            let invalid_day = |message: &str| ParseError::at_keys(contents, &[day_key], message);
+            let day = key_number(day_key, "day")
+                .ok_or_else(|| invalid_day("Expected `day` followed by a number."))?;
+            let parts = parts.as_table()
+                .ok_or_else(|| invalid_day("Expected a table of answers."))?;
+
+            for (part_key, value) in parts {
+                let invalid_part = |message: &str|
+                    ParseError::at_keys(contents, &[day_key, part_key], message);
+                let part = key_number(part_key, "part")
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| invalid_part("Expected `part1` or `part2`."))?;
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Integer(x) => x.to_string(),
+                    _ => return Err(invalid_part("Expected a string or an integer.")),
+                };
+
+                answers.insert((day, part), value);
+            }
+        }
+
+        Ok(ExpectedAnswers(answers))
+    }
+
+    /// Expected answer for one part of a puzzle.
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare an answer to the expected one.
+    pub fn check(&self, answer: &Answer) -> Verdict {
+        match self.get(answer.day, answer.part) {
+            Some(expected) if expected == answer.value => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Missing,
+        }
+    }
+}
+
+/// Outcome of the comparison with the expected answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    /// The answer matches the expected one.
+    Pass,
+    /// The answer doesn't match the expected one (stored in the variant).
+    Fail(String),
+    /// There is no expected answer for this puzzle.
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail(_) => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/* Read the number in keys such as `day12` or `part2`. */
+fn key_number(key: &str, prefix: &str) -> Option<usize> {
+    key.strip_prefix(prefix).and_then(|number| number.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn check_answers() {
+        let expected = ExpectedAnswers::from_toml("[day16]\npart1 = \"Sue 40\"\n\n[day1]\npart2 = 1797\n").unwrap();
+        let answer = |day, part, value: &str| Answer {
+            day,
+            part,
+            value: value.to_string(),
+            duration: Duration::default(),
+        };
+
+        assert_eq!(expected.check(&answer(16, Part::One, "Sue 40")), Verdict::Pass);
+        assert_eq!(expected.check(&answer(1, Part::Two, "1796")), Verdict::Fail(String::from("1797")));
+        assert_eq!(expected.check(&answer(1, Part::One, "280")), Verdict::Missing);
+    }
+}